@@ -0,0 +1,17 @@
+//! Corpora shared by the benchmarks
+
+/// Builds a corpus of roughly `size` bytes by repeating `sample`
+pub fn corpus(sample: &str, size: usize) -> String {
+    sample.repeat(size / sample.len() + 1)
+}
+
+pub fn corpora(size: usize) -> Vec<(&'static str, String)> {
+    vec![
+        (
+            "ascii",
+            corpus("The rocket goes to the moon, and back again. ", size),
+        ),
+        ("cjk", corpus("火箭飞向月球，然后再回来。", size)),
+        ("emoji", corpus("🚀🌑👨‍🚀🌍✨ ", size)),
+    ]
+}
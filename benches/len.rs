@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+#[path = "common/corpus.rs"]
+mod corpus;
+
+use corpus::corpora;
+
+fn bench_len(c: &mut Criterion) {
+    let mut group = c.benchmark_group("len");
+
+    for size in [64, 4 * 1024, 1024 * 1024].iter() {
+        for (name, s) in corpora(*size) {
+            group.throughput(Throughput::Bytes(s.len() as u64));
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("{}/chars_count", name), size),
+                &s,
+                |b, s| b.iter(|| black_box(s.as_str()).chars().count()),
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("{}/utf8_slice", name), size),
+                &s,
+                |b, s| b.iter(|| utf8_slice::len(black_box(s.as_str()))),
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_len);
+criterion_main!(benches);
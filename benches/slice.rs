@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[path = "common/corpus.rs"]
+mod corpus;
+
+/// The original implementation of `slice`, which walks the string up to three
+/// times
+fn previous_slice(s: &str, begin: usize, end: usize) -> &str {
+    if end < begin {
+        return "";
+    }
+
+    s.char_indices()
+        .nth(begin)
+        .and_then(|(start_pos, _)| {
+            if end >= s.chars().count() {
+                return Some(&s[start_pos..]);
+            }
+
+            s[start_pos..]
+                .char_indices()
+                .nth(end - begin)
+                .map(|(end_pos, _)| &s[start_pos..start_pos + end_pos])
+        })
+        .unwrap_or("")
+}
+
+/// The shared corpora, plus text mixing ascii with emoji, where the word at a
+/// time scan keeps switching between its fast and slow path
+fn corpora(size: usize) -> Vec<(&'static str, String)> {
+    let mut corpora = corpus::corpora(size);
+    corpora.push((
+        "mixed",
+        corpus::corpus("The rocket 🚀 goes to the moon 🌑, and back again. ", size),
+    ));
+    corpora
+}
+
+fn bench_slice(c: &mut Criterion) {
+    let mut group = c.benchmark_group("slice");
+
+    for size in [4 * 1024, 1024 * 1024].iter() {
+        for (name, s) in corpora(*size) {
+            let len = s.chars().count();
+            let (begin, end) = (len / 4, len / 2);
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("{}/previous", name), size),
+                &s,
+                |b, s| b.iter(|| previous_slice(black_box(s), black_box(begin), black_box(end))),
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("{}/utf8_slice", name), size),
+                &s,
+                |b, s| b.iter(|| utf8_slice::slice(black_box(s), black_box(begin), black_box(end))),
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_slice);
+criterion_main!(benches);
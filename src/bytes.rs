@@ -0,0 +1,314 @@
+//! Slice utilities for byte slices which may contain invalid UTF-8.
+//!
+//! The lossy functions count every invalid sequence as a single character,
+//! just like [`String::from_utf8_lossy`] replaces every invalid sequence with a
+//! single `U+FFFD REPLACEMENT CHARACTER`. The `_strict` functions instead
+//! return an error when the input is not valid UTF-8.
+//!
+//! All functions return a sub-slice of the original bytes, so no bytes are
+//! ever replaced or copied.
+//!
+//! [`String::from_utf8_lossy`]: https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy
+
+use core::str::Utf8Error;
+
+/// Fetches a slice of a byte string from a begin to an end index
+/// taking into account utf8/unicode character indices.
+///
+/// # Arguments
+///
+/// * `bytes` - An input byte string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// let bytes = b"The \xF0\x9F\x9A\x80 goes to the \xF0\x9F!";
+///
+/// assert_eq!(utf8_slice::bytes::slice(bytes, 4, 5), "🚀".as_bytes());
+/// // The truncated 🌑 counts as a single character
+/// assert_eq!(utf8_slice::bytes::slice(bytes, 18, 19), b"\xF0\x9F");
+/// ```
+///
+/// # Note
+/// * Will return an empty slice for invalid indices *
+pub fn slice(bytes: &[u8], begin: usize, end: usize) -> &[u8] {
+    if end <= begin {
+        return &[];
+    }
+
+    let start_pos = match lossy_pos(bytes, begin) {
+        Some(start_pos) if start_pos < bytes.len() => start_pos,
+        _ => return &[],
+    };
+    let end_pos = lossy_pos(&bytes[start_pos..], end - begin)
+        .map(|end_pos| start_pos + end_pos)
+        .unwrap_or(bytes.len());
+
+    &bytes[start_pos..end_pos]
+}
+
+/// Fetches a slice of a byte string from a starting index
+/// taking into account utf8/unicode character indices.
+///
+/// # Arguments
+///
+/// * `bytes` - An input byte string to take the slice from
+/// * `begin` - Where the slice begins
+///
+/// # Examples
+///
+/// ```
+/// let bytes = b"The \xF0\x9F\x9A\x80 goes to the \xF0\x9F!";
+///
+/// assert_eq!(utf8_slice::bytes::from(bytes, 18), b"\xF0\x9F!");
+/// ```
+///
+/// # Note
+/// * Will return an empty slice for invalid indices *
+pub fn from(bytes: &[u8], begin: usize) -> &[u8] {
+    match lossy_pos(bytes, begin) {
+        Some(start_pos) => &bytes[start_pos..],
+        None => &[],
+    }
+}
+
+/// Fetches a slice of a byte string until an ending index
+/// taking into account utf8/unicode character indices.
+///
+/// # Arguments
+///
+/// * `bytes` - An input byte string to take the slice from
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// let bytes = b"The \xF0\x9F\x9A\x80 goes to the \xF0\x9F!";
+///
+/// assert_eq!(utf8_slice::bytes::till(bytes, 5), "The 🚀".as_bytes());
+/// ```
+pub fn till(bytes: &[u8], end: usize) -> &[u8] {
+    match lossy_pos(bytes, end) {
+        Some(end_pos) => &bytes[..end_pos],
+        None => bytes,
+    }
+}
+
+/// Fetches the length in characters of a byte string
+///
+/// # Arguments
+///
+/// * `bytes` - The byte string of which to fetch the length
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(utf8_slice::bytes::len(b"\xF0\x9F\x9A\x80"), 1);
+/// assert_eq!(utf8_slice::bytes::len(b"\xF0\x9F\x9A"), 1);
+/// assert_eq!(utf8_slice::bytes::len(b"\xFF\xFF"), 2);
+/// ```
+pub fn len(bytes: &[u8]) -> usize {
+    bytes
+        .utf8_chunks()
+        .map(|chunk| crate::len(chunk.valid()) + !chunk.invalid().is_empty() as usize)
+        .sum()
+}
+
+/// Fetches a slice of a byte string from a begin to an end index
+/// taking into account utf8/unicode character indices, failing if the byte
+/// string is not valid UTF-8.
+///
+/// This behaves the same as [`slice`](fn@crate::slice) on the validated
+/// string.
+///
+/// # Arguments
+///
+/// * `bytes` - An input byte string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::bytes::slice_strict;
+///
+/// assert_eq!(slice_strict("The 🚀".as_bytes(), 4, 5), Ok("🚀".as_bytes()));
+/// assert!(slice_strict(b"The \xF0\x9F", 4, 5).is_err());
+/// ```
+pub fn slice_strict(bytes: &[u8], begin: usize, end: usize) -> Result<&[u8], Utf8Error> {
+    core::str::from_utf8(bytes).map(|s| crate::slice(s, begin, end).as_bytes())
+}
+
+/// Fetches a slice of a byte string from a starting index
+/// taking into account utf8/unicode character indices, failing if the byte
+/// string is not valid UTF-8.
+///
+/// This behaves the same as [`from`](crate::from) on the validated string.
+///
+/// # Arguments
+///
+/// * `bytes` - An input byte string to take the slice from
+/// * `begin` - Where the slice begins
+pub fn from_strict(bytes: &[u8], begin: usize) -> Result<&[u8], Utf8Error> {
+    core::str::from_utf8(bytes).map(|s| crate::from(s, begin).as_bytes())
+}
+
+/// Fetches a slice of a byte string until an ending index
+/// taking into account utf8/unicode character indices, failing if the byte
+/// string is not valid UTF-8.
+///
+/// This behaves the same as [`till`](crate::till) on the validated string.
+///
+/// # Arguments
+///
+/// * `bytes` - An input byte string to take the slice from
+/// * `end` - Where the slice ends
+pub fn till_strict(bytes: &[u8], end: usize) -> Result<&[u8], Utf8Error> {
+    core::str::from_utf8(bytes).map(|s| crate::till(s, end).as_bytes())
+}
+
+/// Fetches the length in characters of a byte string, failing if the byte
+/// string is not valid UTF-8.
+///
+/// # Arguments
+///
+/// * `bytes` - The byte string of which to fetch the length
+pub fn len_strict(bytes: &[u8]) -> Result<usize, Utf8Error> {
+    core::str::from_utf8(bytes).map(crate::len)
+}
+
+/// Finds the byte position at which the character with index `char_idx`
+/// starts, where every invalid sequence counts as one character.
+///
+/// The amount of characters is a valid index and maps to the length of the
+/// byte string.
+fn lossy_pos(bytes: &[u8], mut char_idx: usize) -> Option<usize> {
+    let mut pos = 0;
+    for chunk in bytes.utf8_chunks() {
+        let valid = chunk.valid();
+        let valid_len = crate::len(valid);
+        if char_idx < valid_len {
+            return crate::char_to_byte(valid, char_idx).map(|offset| pos + offset);
+        }
+        char_idx -= valid_len;
+        pos += valid.len();
+
+        if !chunk.invalid().is_empty() {
+            if char_idx == 0 {
+                return Some(pos);
+            }
+            char_idx -= 1;
+            pos += chunk.invalid().len();
+        }
+    }
+
+    if char_idx == 0 {
+        Some(bytes.len())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::String;
+    use std::vec::Vec;
+
+    const INPUTS: [&[u8]; 7] = [
+        b"",
+        b"abc",
+        "\u{345}ab\u{898}xyz".as_bytes(),
+        b"a\xF0\x9F\x9A\x80b\xF0\x9Fc",
+        b"\xFF\xFE\xFD",
+        b"\xE0\x80\x80ab\xED\xA0\x80",
+        b"\xC3",
+    ];
+
+    /// Splits bytes into the parts which `String::from_utf8_lossy` turns into
+    /// a single character each
+    fn lossy_chars(bytes: &[u8]) -> Vec<&[u8]> {
+        let mut chars = Vec::new();
+        for chunk in bytes.utf8_chunks() {
+            let valid = chunk.valid();
+            chars.extend(
+                valid
+                    .char_indices()
+                    .map(|(pos, c)| &valid.as_bytes()[pos..pos + c.len_utf8()]),
+            );
+            if !chunk.invalid().is_empty() {
+                chars.push(chunk.invalid());
+            }
+        }
+        chars
+    }
+
+    #[test]
+    fn test_same_as_lossy() {
+        for bytes in INPUTS.iter() {
+            let chars = lossy_chars(bytes);
+            assert_eq!(len(bytes), String::from_utf8_lossy(bytes).chars().count());
+            assert_eq!(len(bytes), chars.len());
+
+            for i in 0..chars.len() + 2 {
+                for j in 0..chars.len() + 2 {
+                    let expected: Vec<u8> = if i < j {
+                        chars
+                            .iter()
+                            .skip(i)
+                            .take(j - i)
+                            .flat_map(|c| c.iter().copied())
+                            .collect()
+                    } else {
+                        Vec::new()
+                    };
+                    assert_eq!(slice(bytes, i, j), &expected[..]);
+                }
+                assert_eq!(from(bytes, i), slice(bytes, i, usize::MAX));
+                assert_eq!(till(bytes, i), slice(bytes, 0, i));
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_as_str_for_valid_utf8() {
+        let s = "\u{345}ab\u{898}xyz";
+        for i in 0..10 {
+            for j in 0..10 {
+                assert_eq!(slice(s.as_bytes(), i, j), crate::slice(s, i, j).as_bytes());
+                assert_eq!(
+                    slice_strict(s.as_bytes(), i, j),
+                    Ok(crate::slice(s, i, j).as_bytes())
+                );
+            }
+            assert_eq!(from(s.as_bytes(), i), crate::from(s, i).as_bytes());
+            assert_eq!(till(s.as_bytes(), i), crate::till(s, i).as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        let bytes = b"a\xF0\x9F\x9A\x80b\xF0\x9Fc";
+        assert_eq!(slice(bytes, 1, 2), "🚀".as_bytes());
+        assert_eq!(slice(bytes, 3, 4), b"\xF0\x9F");
+        assert_eq!(slice(bytes, 3, 10), b"\xF0\x9Fc");
+        assert_eq!(slice(bytes, 5, 6), b"");
+        assert_eq!(slice(b"\xFF\xFE", 1, 2), b"\xFE");
+    }
+
+    #[test]
+    fn test_strict() {
+        let bytes = b"a\xF0\x9F\x9A\x80b\xF0\x9Fc";
+        assert!(slice_strict(bytes, 0, 1).is_err());
+        assert!(from_strict(bytes, 0).is_err());
+        assert!(till_strict(bytes, 0).is_err());
+        assert!(len_strict(bytes).is_err());
+
+        let bytes = "a🚀b".as_bytes();
+        assert_eq!(from_strict(bytes, 1), Ok("🚀b".as_bytes()));
+        assert_eq!(till_strict(bytes, 2), Ok("a🚀".as_bytes()));
+        assert_eq!(len_strict(bytes), Ok(3));
+    }
+}
@@ -0,0 +1,241 @@
+use alloc::vec::Vec;
+
+/// A precomputed index over the characters of a string, which allows for
+/// taking many slices of the same string without walking it from the start
+/// every time.
+///
+/// The index stores the byte position of every `interval`-th character. A
+/// lookup jumps to the nearest checkpoint before the requested character and
+/// walks at most `interval - 1` characters from there. A smaller interval
+/// makes lookups faster at the cost of memory.
+///
+/// This type is only available with the `alloc` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::CharIndex;
+///
+/// let s = "The 🚀 goes to the 🌑!";
+/// let index = CharIndex::new(s);
+///
+/// assert_eq!(index.len(), 20);
+/// assert_eq!(index.slice(4, 5), "🚀");
+/// assert_eq!(index.from(18), "🌑!");
+/// assert_eq!(index.till(5), "The 🚀");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CharIndex<'a> {
+    s: &'a str,
+    interval: usize,
+    checkpoints: Vec<usize>,
+    len: usize,
+}
+
+impl<'a> CharIndex<'a> {
+    /// The checkpoint interval used by [`CharIndex::new`]
+    pub const DEFAULT_INTERVAL: usize = 64;
+
+    /// Builds an index over `s` with the default checkpoint interval
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to index
+    pub fn new(s: &'a str) -> Self {
+        Self::with_interval(s, Self::DEFAULT_INTERVAL)
+    }
+
+    /// Builds an index over `s` which stores a checkpoint every `interval`
+    /// characters
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to index
+    /// * `interval` - The amount of characters between two checkpoints
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    pub fn with_interval(s: &'a str, interval: usize) -> Self {
+        assert!(interval != 0, "checkpoint interval must be non-zero");
+
+        // Sizing the checkpoints by the byte length would over-allocate up to
+        // four times for non-ascii text
+        let len = crate::len(s);
+        let mut checkpoints = Vec::with_capacity(len.div_ceil(interval));
+        checkpoints.extend(s.char_indices().step_by(interval).map(|(pos, _)| pos));
+
+        CharIndex {
+            s,
+            interval,
+            checkpoints,
+            len,
+        }
+    }
+
+    /// Returns the indexed string
+    pub fn as_str(&self) -> &'a str {
+        self.s
+    }
+
+    /// Returns the amount of characters between two checkpoints
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+
+    /// Fetches the length in characters of the indexed string
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the indexed string is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Fetches the byte position of a character index
+    ///
+    /// The length of the string is a valid index and maps to the byte length
+    /// of the string.
+    ///
+    /// # Arguments
+    ///
+    /// * `char_idx` - The character index to look up
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let index = utf8_slice::CharIndex::new("🚀 to 🌑");
+    ///
+    /// assert_eq!(index.byte_pos(1), Some(4));
+    /// assert_eq!(index.byte_pos(6), Some(12));
+    /// assert_eq!(index.byte_pos(7), None);
+    /// ```
+    pub fn byte_pos(&self, char_idx: usize) -> Option<usize> {
+        if char_idx == self.len {
+            return Some(self.s.len());
+        }
+
+        if self.len == self.s.len() {
+            return Some(char_idx).filter(|&idx| idx < self.len);
+        }
+
+        let checkpoint = *self.checkpoints.get(char_idx / self.interval)?;
+
+        self.s[checkpoint..]
+            .char_indices()
+            .nth(char_idx % self.interval)
+            .map(|(pos, _)| checkpoint + pos)
+    }
+
+    /// Fetches a slice of the indexed string from a begin to an end index
+    ///
+    /// This behaves the same as [`slice`](crate::slice).
+    ///
+    /// # Arguments
+    ///
+    /// * `begin` - Where the slice begins
+    /// * `end` - Where the slice ends
+    ///
+    /// # Note
+    /// * Will return an empty string for invalid indices *
+    pub fn slice(&self, begin: usize, end: usize) -> &'a str {
+        if end < begin || begin >= self.len {
+            return "";
+        }
+
+        let end = end.min(self.len);
+        match (self.byte_pos(begin), self.byte_pos(end)) {
+            (Some(start_pos), Some(end_pos)) => &self.s[start_pos..end_pos],
+            _ => "",
+        }
+    }
+
+    /// Fetches a slice of the indexed string from a starting index
+    ///
+    /// This behaves the same as [`from`](crate::from).
+    ///
+    /// # Arguments
+    ///
+    /// * `begin` - Where the slice begins
+    ///
+    /// # Note
+    /// * Will return an empty string for invalid indices *
+    pub fn from(&self, begin: usize) -> &'a str {
+        self.slice(begin, self.len)
+    }
+
+    /// Fetches a slice of the indexed string until an ending index
+    ///
+    /// This behaves the same as [`till`](crate::till).
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - Where the slice ends
+    ///
+    /// # Note
+    /// * Will return an empty string for invalid indices *
+    pub fn till(&self, end: usize) -> &'a str {
+        self.slice(0, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_same_as_slice, STRINGS};
+
+    #[test]
+    fn test_same_as_slice() {
+        for s in STRINGS.iter() {
+            for interval in 1..6 {
+                let index = CharIndex::with_interval(s, interval);
+                assert_same_as_slice(
+                    s,
+                    |i, j| index.slice(i, j),
+                    |i| index.from(i),
+                    |i| index.till(i),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_len() {
+        for s in STRINGS.iter() {
+            let index = CharIndex::new(s);
+            assert_eq!(index.len(), crate::len(s));
+            assert_eq!(index.is_empty(), s.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_byte_pos() {
+        let index = CharIndex::with_interval("\u{345}ab\u{898}xyz", 2);
+        assert_eq!(index.byte_pos(0), Some(0));
+        assert_eq!(index.byte_pos(1), Some(2));
+        assert_eq!(index.byte_pos(3), Some(4));
+        assert_eq!(index.byte_pos(4), Some(7));
+        assert_eq!(index.byte_pos(7), Some(10));
+        assert_eq!(index.byte_pos(8), None);
+
+        let index = CharIndex::new("abc");
+        assert_eq!(index.byte_pos(2), Some(2));
+        assert_eq!(index.byte_pos(3), Some(3));
+        assert_eq!(index.byte_pos(4), None);
+    }
+
+    #[test]
+    fn test_checkpoints() {
+        let s = "火箭飞向月球，然后再回来。";
+        let index = CharIndex::with_interval(s, 4);
+        assert_eq!(index.checkpoints, [0, 12, 24, 36]);
+        assert!(index.checkpoints.capacity() < s.len() / 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_interval() {
+        CharIndex::with_interval("abc", 0);
+    }
+}
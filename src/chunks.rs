@@ -0,0 +1,551 @@
+use core::iter::FusedIterator;
+
+use crate::count;
+
+/// Iterates over consecutive slices of `n` characters of a string, starting
+/// at the beginning of the string
+///
+/// The last slice is shorter than `n` characters if the length of the string
+/// is not a multiple of `n`. Every character is only visited once, so the
+/// whole iteration takes linear time.
+///
+/// # Arguments
+///
+/// * `s` - The string to split into chunks
+/// * `n` - The amount of characters per chunk
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let chunks: Vec<&str> = utf8_slice::chunks(s, 6).collect();
+/// assert_eq!(chunks, ["The 🚀 ", "goes t", "o the ", "🌑!"]);
+///
+/// let chunks: Vec<&str> = utf8_slice::chunks(s, 6).rev().collect();
+/// assert_eq!(chunks, ["🌑!", "o the ", "goes t", "The 🚀 "]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+pub fn chunks(s: &str, n: usize) -> Chunks<'_> {
+    assert!(n != 0, "chunk size must be non-zero");
+
+    Chunks {
+        rest: s,
+        n,
+        len: None,
+    }
+}
+
+/// Iterates over consecutive slices of exactly `n` characters of a string,
+/// starting at the beginning of the string
+///
+/// If the length of the string is not a multiple of `n`, the last characters
+/// are not yielded but can be fetched with [`ChunksExact::remainder`].
+///
+/// # Arguments
+///
+/// * `s` - The string to split into chunks
+/// * `n` - The amount of characters per chunk
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let mut chunks = utf8_slice::chunks_exact(s, 6);
+/// assert_eq!(chunks.next(), Some("The 🚀 "));
+/// assert_eq!(chunks.next_back(), Some("o the "));
+/// assert_eq!(chunks.remainder(), "🌑!");
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+pub fn chunks_exact(s: &str, n: usize) -> ChunksExact<'_> {
+    assert!(n != 0, "chunk size must be non-zero");
+
+    let len = count::count_chars(s);
+    let (rest, remainder) = s.split_at(back_pos(s, len % n));
+
+    ChunksExact {
+        rest,
+        remainder,
+        n,
+        len: len - len % n,
+    }
+}
+
+/// Iterates over consecutive slices of `n` characters of a string, starting
+/// at the end of the string
+///
+/// The last slice is shorter than `n` characters if the length of the string
+/// is not a multiple of `n`.
+///
+/// # Arguments
+///
+/// * `s` - The string to split into chunks
+/// * `n` - The amount of characters per chunk
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let chunks: Vec<&str> = utf8_slice::rchunks(s, 6).collect();
+/// assert_eq!(chunks, ["the 🌑!", "es to ", "e 🚀 go", "Th"]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+pub fn rchunks(s: &str, n: usize) -> RChunks<'_> {
+    assert!(n != 0, "chunk size must be non-zero");
+
+    RChunks {
+        rest: s,
+        n,
+        len: None,
+    }
+}
+
+/// Iterates over all overlapping slices of `n` characters of a string
+///
+/// Nothing is yielded if the string is shorter than `n` characters. Moving
+/// the window only looks at the character which leaves and the character
+/// which enters it, so the whole iteration takes linear time.
+///
+/// # Arguments
+///
+/// * `s` - The string to take the windows from
+/// * `n` - The amount of characters per window
+///
+/// # Examples
+///
+/// ```
+/// let windows: Vec<&str> = utf8_slice::windows("🚀 to 🌑", 5).collect();
+/// assert_eq!(windows, ["🚀 to ", " to 🌑"]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+pub fn windows(s: &str, n: usize) -> Windows<'_> {
+    assert!(n != 0, "window size must be non-zero");
+
+    Windows {
+        s,
+        n,
+        front: count::char_pos(s, n).map(|end| (0, end)),
+        back: None,
+    }
+}
+
+/// The byte position `n` characters before the end of `s`, or 0 if `s` is
+/// shorter than that
+fn back_pos(s: &str, n: usize) -> usize {
+    match n.checked_sub(1) {
+        None => s.len(),
+        Some(n) => s.char_indices().rev().nth(n).map_or(0, |(pos, _)| pos),
+    }
+}
+
+/// The length in bytes of the character starting at `pos`
+fn next_char_len(s: &str, pos: usize) -> usize {
+    s[pos..].chars().next().map_or(0, char::len_utf8)
+}
+
+/// The length in bytes of the character ending at `pos`
+fn prev_char_len(s: &str, pos: usize) -> usize {
+    s[..pos].chars().next_back().map_or(0, char::len_utf8)
+}
+
+/// Bounds on the amount of chunks of `n` characters in `s` if its length in
+/// characters is unknown
+fn chunk_bounds(s: &str, n: usize) -> (usize, Option<usize>) {
+    // A character takes up between 1 and 4 bytes
+    let min_chars = s.len().div_ceil(4);
+    (min_chars.div_ceil(n), Some(s.len().div_ceil(n)))
+}
+
+/// An iterator over slices of `n` characters, starting at the beginning of
+/// the string
+///
+/// This struct is created by [`chunks`].
+#[derive(Debug, Clone)]
+pub struct Chunks<'a> {
+    rest: &'a str,
+    n: usize,
+    /// The length of `rest` in characters, which is only counted once it is
+    /// needed to iterate from the back
+    len: Option<usize>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (chunk, rest) = self
+            .rest
+            .split_at(count::char_pos(self.rest, self.n).unwrap_or(self.rest.len()));
+        self.rest = rest;
+        if let Some(len) = self.len.as_mut() {
+            *len -= (*len).min(self.n);
+        }
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len {
+            Some(len) => {
+                let chunks = len.div_ceil(self.n);
+                (chunks, Some(chunks))
+            }
+            None => chunk_bounds(self.rest, self.n),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Chunks<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rest = self.rest;
+        let len = self.len.get_or_insert_with(|| count::count_chars(rest));
+        if *len == 0 {
+            return None;
+        }
+
+        let chunk_len = match *len % self.n {
+            0 => self.n,
+            partial => partial,
+        };
+        *len -= chunk_len;
+
+        let (rest, chunk) = rest.split_at(back_pos(rest, chunk_len));
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+impl FusedIterator for Chunks<'_> {}
+
+/// An iterator over slices of exactly `n` characters, starting at the
+/// beginning of the string
+///
+/// This struct is created by [`chunks_exact`].
+#[derive(Debug, Clone)]
+pub struct ChunksExact<'a> {
+    rest: &'a str,
+    remainder: &'a str,
+    n: usize,
+    /// The length of `rest` in characters
+    len: usize,
+}
+
+impl<'a> ChunksExact<'a> {
+    /// Returns the characters at the end of the string which do not fill up
+    /// a whole chunk
+    pub fn remainder(&self) -> &'a str {
+        self.remainder
+    }
+}
+
+impl<'a> Iterator for ChunksExact<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let (chunk, rest) = self
+            .rest
+            .split_at(count::char_pos(self.rest, self.n).unwrap_or(self.rest.len()));
+        self.rest = rest;
+        self.len -= self.n;
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len / self.n, Some(self.len / self.n))
+    }
+}
+
+impl DoubleEndedIterator for ChunksExact<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let (rest, chunk) = self.rest.split_at(back_pos(self.rest, self.n));
+        self.rest = rest;
+        self.len -= self.n;
+
+        Some(chunk)
+    }
+}
+
+impl ExactSizeIterator for ChunksExact<'_> {}
+
+impl FusedIterator for ChunksExact<'_> {}
+
+/// An iterator over slices of `n` characters, starting at the end of the
+/// string
+///
+/// This struct is created by [`rchunks`].
+#[derive(Debug, Clone)]
+pub struct RChunks<'a> {
+    rest: &'a str,
+    n: usize,
+    /// The length of `rest` in characters, which is only counted once it is
+    /// needed to iterate from the front
+    len: Option<usize>,
+}
+
+impl<'a> Iterator for RChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (rest, chunk) = self.rest.split_at(back_pos(self.rest, self.n));
+        self.rest = rest;
+        if let Some(len) = self.len.as_mut() {
+            *len -= (*len).min(self.n);
+        }
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len {
+            Some(len) => {
+                let chunks = len.div_ceil(self.n);
+                (chunks, Some(chunks))
+            }
+            None => chunk_bounds(self.rest, self.n),
+        }
+    }
+}
+
+impl DoubleEndedIterator for RChunks<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rest = self.rest;
+        let len = self.len.get_or_insert_with(|| count::count_chars(rest));
+        if *len == 0 {
+            return None;
+        }
+
+        let chunk_len = match *len % self.n {
+            0 => self.n,
+            partial => partial,
+        };
+        *len -= chunk_len;
+
+        let (chunk, rest) = rest.split_at(count::char_pos(rest, chunk_len).unwrap_or(rest.len()));
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+impl FusedIterator for RChunks<'_> {}
+
+/// An iterator over all overlapping slices of `n` characters
+///
+/// This struct is created by [`windows`].
+#[derive(Debug, Clone)]
+pub struct Windows<'a> {
+    s: &'a str,
+    n: usize,
+    /// The byte range of the next window from the front, or `None` once the
+    /// iterator is exhausted
+    front: Option<(usize, usize)>,
+    /// The byte range of the next window from the back, which is only looked
+    /// up once it is needed
+    back: Option<(usize, usize)>,
+}
+
+impl<'a> Iterator for Windows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.front?;
+        let window = &self.s[start..end];
+
+        let last = match self.back {
+            Some((back_start, _)) => start == back_start,
+            None => end == self.s.len(),
+        };
+        self.front = if last {
+            None
+        } else {
+            Some((
+                start + next_char_len(self.s, start),
+                end + next_char_len(self.s, end),
+            ))
+        };
+
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining window starts at a character between the start of
+        // the front window and the start of the last window
+        let bytes = match (self.front, self.back) {
+            (None, _) => return (0, Some(0)),
+            (Some((start, _)), Some((back_start, _))) => back_start - start,
+            (Some((_, end)), None) => self.s.len() - end,
+        };
+
+        (bytes.div_ceil(4) + 1, Some(bytes + 1))
+    }
+}
+
+impl DoubleEndedIterator for Windows<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (start, _) = self.front?;
+        let s = self.s;
+        let n = self.n;
+        let (back_start, back_end) = *self.back.get_or_insert_with(|| (back_pos(s, n), s.len()));
+
+        if back_start == start {
+            self.front = None;
+        } else {
+            self.back = Some((
+                back_start - prev_char_len(s, back_start),
+                back_end - prev_char_len(s, back_end),
+            ));
+        }
+
+        Some(&s[back_start..back_end])
+    }
+}
+
+impl FusedIterator for Windows<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::STRINGS;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_chunks_same_as_slice() {
+        for s in STRINGS.iter() {
+            let len = crate::len(s);
+            for n in 1..len + 2 {
+                let expected: Vec<&str> = (0..len)
+                    .step_by(n)
+                    .map(|begin| crate::slice(s, begin, begin + n))
+                    .collect();
+
+                assert_eq!(chunks(s, n).collect::<Vec<_>>(), expected);
+                let mut reversed: Vec<&str> = chunks(s, n).rev().collect();
+                reversed.reverse();
+                assert_eq!(reversed, expected);
+
+                let exact: Vec<&str> = chunks_exact(s, n).collect();
+                let full = len / n;
+                assert_eq!(exact, expected[..full]);
+                assert_eq!(chunks_exact(s, n).len(), full);
+                assert_eq!(chunks_exact(s, n).remainder(), crate::from(s, full * n));
+                let mut reversed: Vec<&str> = chunks_exact(s, n).rev().collect();
+                reversed.reverse();
+                assert_eq!(reversed, exact);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rchunks_same_as_slice() {
+        for s in STRINGS.iter() {
+            let len = crate::len(s);
+            for n in 1..len + 2 {
+                let expected: Vec<&str> = (0..len)
+                    .step_by(n)
+                    .map(|from_end| {
+                        crate::slice(s, len.saturating_sub(from_end + n), len - from_end)
+                    })
+                    .collect();
+
+                assert_eq!(rchunks(s, n).collect::<Vec<_>>(), expected);
+                let mut reversed: Vec<&str> = rchunks(s, n).rev().collect();
+                reversed.reverse();
+                assert_eq!(reversed, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_windows_same_as_slice() {
+        for s in STRINGS.iter() {
+            let len = crate::len(s);
+            for n in 1..len + 2 {
+                let expected: Vec<&str> = (0..(len + 1).saturating_sub(n))
+                    .map(|begin| crate::slice(s, begin, begin + n))
+                    .collect();
+
+                assert_eq!(windows(s, n).collect::<Vec<_>>(), expected);
+                let mut reversed: Vec<&str> = windows(s, n).rev().collect();
+                reversed.reverse();
+                assert_eq!(reversed, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mixed_ends() {
+        let s = "\u{345}ab\u{898}xyz";
+
+        let mut iter = chunks(s, 2);
+        assert_eq!(iter.next(), Some("\u{345}a"));
+        assert_eq!(iter.next_back(), Some("z"));
+        assert_eq!(iter.next(), Some("b\u{898}"));
+        assert_eq!(iter.next_back(), Some("xy"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = rchunks(s, 3);
+        assert_eq!(iter.next(), Some("xyz"));
+        assert_eq!(iter.next_back(), Some("\u{345}"));
+        assert_eq!(iter.next(), Some("ab\u{898}"));
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = windows(s, 5);
+        assert_eq!(iter.next_back(), Some("b\u{898}xyz"));
+        assert_eq!(iter.next(), Some("\u{345}ab\u{898}x"));
+        assert_eq!(iter.next(), Some("ab\u{898}xy"));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_size_hint() {
+        for s in STRINGS.iter() {
+            for n in 1..5 {
+                let (lower, upper) = chunks(s, n).size_hint();
+                let count = chunks(s, n).count();
+                assert!(lower <= count && count <= upper.unwrap());
+
+                let (lower, upper) = windows(s, n).size_hint();
+                let count = windows(s, n).count();
+                assert!(lower <= count && count <= upper.unwrap());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_size() {
+        chunks("abc", 0);
+    }
+}
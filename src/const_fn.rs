@@ -0,0 +1,191 @@
+//! `const fn` versions of the slice utilities.
+//!
+//! These can be used to derive constants from other constants at compile time.
+//! They decode the string byte by byte without the help of iterators, so the
+//! top-level functions should be preferred at runtime.
+//!
+//! # Examples
+//!
+//! ```
+//! use utf8_slice::const_fn;
+//!
+//! const GREETING: &str = "Grüße aus dem 🚀!";
+//! const SHORT: &str = const_fn::till(GREETING, 5);
+//! const LEN: usize = const_fn::len(GREETING);
+//!
+//! assert_eq!(SHORT, "Grüße");
+//! assert_eq!(LEN, 16);
+//! ```
+
+use crate::count::is_continuation_byte;
+
+/// Fetches a slice of a string from a begin to an end index
+/// taking into account utf8/unicode character indices.
+///
+/// This behaves the same as [`slice`](fn@crate::slice).
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// const ROCKET: &str = utf8_slice::const_fn::slice("The 🚀 goes to the 🌑!", 4, 5);
+/// # assert_eq!(ROCKET, "🚀");
+/// // Will equal "🚀"
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub const fn slice(s: &str, begin: usize, end: usize) -> &str {
+    if end < begin {
+        return "";
+    }
+
+    let start_pos = match byte_pos(s.as_bytes(), begin) {
+        Some(start_pos) if start_pos < s.len() => start_pos,
+        _ => return "",
+    };
+    let end_pos = match byte_pos(s.as_bytes(), end) {
+        Some(end_pos) => end_pos,
+        None => s.len(),
+    };
+
+    sub_str(s, start_pos, end_pos)
+}
+
+/// Fetches a slice of a string from a starting index
+/// taking into account utf8/unicode character indices.
+///
+/// This behaves the same as [`from`](crate::from).
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+///
+/// # Examples
+///
+/// ```
+/// const THE_MOON: &str = utf8_slice::const_fn::from("The 🚀 goes to the 🌑!", 18);
+/// # assert_eq!(THE_MOON, "🌑!");
+/// // Will equal "🌑!"
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub const fn from(s: &str, begin: usize) -> &str {
+    slice(s, begin, usize::MAX)
+}
+
+/// Fetches a slice of a string until an ending index
+/// taking into account utf8/unicode character indices.
+///
+/// This behaves the same as [`till`](crate::till).
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// const THE_ROCKET: &str = utf8_slice::const_fn::till("The 🚀 goes to the 🌑!", 5);
+/// # assert_eq!(THE_ROCKET, "The 🚀");
+/// // Will equal "The 🚀"
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub const fn till(s: &str, end: usize) -> &str {
+    slice(s, 0, end)
+}
+
+/// Fetches the length in characters of an utf8/unicode string
+///
+/// This behaves the same as [`len`](crate::len).
+///
+/// # Arguments
+///
+/// * `s` - The string of which to fetch the length
+pub const fn len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_continuation_byte(bytes[i]) {
+            len += 1;
+        }
+        i += 1;
+    }
+
+    len
+}
+
+/// Finds the byte position of a character index, where the length of the
+/// string in characters maps to the length in bytes.
+const fn byte_pos(bytes: &[u8], char_idx: usize) -> Option<usize> {
+    let mut chars = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_continuation_byte(bytes[i]) {
+            if chars == char_idx {
+                return Some(i);
+            }
+            chars += 1;
+        }
+        i += 1;
+    }
+
+    if chars == char_idx {
+        Some(bytes.len())
+    } else {
+        None
+    }
+}
+
+/// Takes `&s[start_pos..end_pos]` for two character boundaries
+const fn sub_str(s: &str, start_pos: usize, end_pos: usize) -> &str {
+    let (head, _) = s.as_bytes().split_at(end_pos);
+    let (_, bytes) = head.split_at(start_pos);
+
+    match core::str::from_utf8(bytes) {
+        Ok(sub_str) => sub_str,
+        Err(_) => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_same_as_slice, STRINGS};
+
+    #[test]
+    fn test_same_as_runtime() {
+        for s in STRINGS.iter() {
+            assert_eq!(len(s), crate::len(s));
+            assert_same_as_slice(s, |i, j| slice(s, i, j), |i| from(s, i), |i| till(s, i));
+        }
+    }
+
+    #[test]
+    fn test_const_context() {
+        const S: &str = "\u{345}ab\u{898}xyz";
+        const SLICE: &str = slice(S, 1, 4);
+        const FROM: &str = from(S, 3);
+        const TILL: &str = till(S, 3);
+        const LEN: usize = len(S);
+        static STATIC_SLICE: &str = slice(S, 4, 10);
+
+        assert_eq!(SLICE, "ab\u{898}");
+        assert_eq!(FROM, "\u{898}xyz");
+        assert_eq!(TILL, "\u{345}ab");
+        assert_eq!(LEN, 7);
+        assert_eq!(STATIC_SLICE, "xyz");
+    }
+}
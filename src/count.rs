@@ -0,0 +1,197 @@
+//! Word-at-a-time character counting.
+//!
+//! A character starts at every byte which is not a continuation byte
+//! (`0b10xx_xxxx`), so counting or skipping characters comes down to counting
+//! those bytes. Instead of looking at one byte at a time, the bytes are loaded
+//! a `usize` at a time and all of the bytes in the word are classified at
+//! once.
+
+use core::convert::TryInto;
+use core::mem::size_of;
+
+const WORD_SIZE: usize = size_of::<usize>();
+
+/// `0x0101...01`, the lowest bit of every byte in a word
+const LSB: usize = usize::MAX / 0xFF;
+
+/// `0x0001...0001`, the lowest bit of every two byte lane in a word
+#[cfg(feature = "simd")]
+const LSB_SHORTS: usize = usize::MAX / 0xFFFF;
+
+/// The amount of words the unrolled loop handles per iteration
+#[cfg(feature = "simd")]
+const UNROLL: usize = 4;
+
+/// The amount of unrolled iterations after which the per-byte counters have to
+/// be flushed, since every iteration adds at most `UNROLL` to each byte.
+#[cfg(feature = "simd")]
+const FLUSH_INTERVAL: usize = 255 / UNROLL;
+
+/// Counts the characters in a string
+pub(crate) fn count_chars(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    #[cfg(feature = "simd")]
+    let (mut count, bytes) = count_unrolled(bytes);
+    #[cfg(not(feature = "simd"))]
+    let mut count = 0;
+
+    let words = bytes.chunks_exact(WORD_SIZE);
+    let tail = words.remainder();
+
+    for word in words {
+        count += char_starts(load(word)).count_ones() as usize;
+    }
+
+    count
+        + tail
+            .iter()
+            .filter(|&&byte| !is_continuation_byte(byte))
+            .count()
+}
+
+/// Finds the byte position at which the character with index `char_idx`
+/// starts
+///
+/// The amount of characters in the string is a valid index and maps to the
+/// length of the string in bytes.
+pub(crate) fn char_pos(s: &str, mut char_idx: usize) -> Option<usize> {
+    let bytes = s.as_bytes();
+
+    // Skip whole words for as long as the character lies beyond them
+    let mut pos = 0;
+    for word in bytes.chunks_exact(WORD_SIZE) {
+        let starts = char_starts(load(word)).count_ones() as usize;
+        if char_idx < starts {
+            break;
+        }
+
+        char_idx -= starts;
+        pos += WORD_SIZE;
+    }
+
+    for (offset, &byte) in bytes[pos..].iter().enumerate() {
+        if !is_continuation_byte(byte) {
+            if char_idx == 0 {
+                return Some(pos + offset);
+            }
+            char_idx -= 1;
+        }
+    }
+
+    if char_idx == 0 {
+        Some(bytes.len())
+    } else {
+        None
+    }
+}
+
+/// Counts the characters in the largest prefix of `bytes` which consists of a
+/// whole amount of unrolled iterations, and returns the count together with
+/// the bytes which are left.
+///
+/// The per-word results are summed bytewise in a single accumulator instead of
+/// calling `count_ones` for every word, which keeps the inner loop free of
+/// dependencies between the words.
+#[cfg(feature = "simd")]
+fn count_unrolled(bytes: &[u8]) -> (usize, &[u8]) {
+    let mut count = 0;
+    let mut rest = bytes;
+    while rest.len() >= WORD_SIZE * UNROLL {
+        let (batch, next) = rest
+            .split_at((rest.len() / (WORD_SIZE * UNROLL)).min(FLUSH_INTERVAL) * WORD_SIZE * UNROLL);
+
+        let mut acc = 0;
+        for block in batch.chunks_exact(WORD_SIZE * UNROLL) {
+            let (a, b) = block.split_at(2 * WORD_SIZE);
+            let (a0, a1) = a.split_at(WORD_SIZE);
+            let (b0, b1) = b.split_at(WORD_SIZE);
+
+            acc += char_starts(load(a0)) + char_starts(load(a1));
+            acc += char_starts(load(b0)) + char_starts(load(b1));
+        }
+
+        count += sum_bytes(acc);
+        rest = next;
+    }
+
+    (count, rest)
+}
+
+/// Sums all bytes of a word
+#[cfg(feature = "simd")]
+fn sum_bytes(word: usize) -> usize {
+    let low = word & (LSB_SHORTS * 0xFF);
+    let high = (word >> 8) & (LSB_SHORTS * 0xFF);
+
+    (low + high).wrapping_mul(LSB_SHORTS) >> ((WORD_SIZE - 2) * 8)
+}
+
+/// Loads a word from a slice of exactly `WORD_SIZE` bytes
+fn load(bytes: &[u8]) -> usize {
+    usize::from_ne_bytes(bytes.try_into().unwrap())
+}
+
+/// Sets the lowest bit of every byte in the word which starts a character
+fn char_starts(word: usize) -> usize {
+    ((!word >> 7) | (word >> 6)) & LSB
+}
+
+/// Returns whether a byte continues a multi-byte utf8 sequence
+pub(crate) const fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::String;
+
+    #[test]
+    fn test_same_as_chars_count() {
+        let parts = ["a", "ö", "中", "🚀", "\u{345}", "xyz", "👨‍🚀"];
+
+        let mut s = String::new();
+        for i in 0..2000 {
+            assert_eq!(count_chars(&s), s.chars().count());
+            s.push_str(parts[i % parts.len()]);
+        }
+    }
+
+    #[test]
+    fn test_unaligned() {
+        let s = "a🚀中öbc".repeat(100);
+        for i in 0..s.len() {
+            if s.is_char_boundary(i) {
+                assert_eq!(count_chars(&s[i..]), s[i..].chars().count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_char_pos() {
+        let s = "a🚀中öbc".repeat(10);
+        let positions: std::vec::Vec<usize> = s
+            .char_indices()
+            .map(|(pos, _)| pos)
+            .chain(Some(s.len()))
+            .collect();
+
+        for (char_idx, &pos) in positions.iter().enumerate() {
+            assert_eq!(char_pos(&s, char_idx), Some(pos));
+        }
+        assert_eq!(char_pos(&s, positions.len()), None);
+        assert_eq!(char_pos("", 0), Some(0));
+        assert_eq!(char_pos("", 1), None);
+    }
+
+    #[test]
+    fn test_char_starts() {
+        assert_eq!(char_starts(0), LSB);
+        assert_eq!(char_starts(usize::MAX), LSB);
+        assert_eq!(char_starts(LSB * 0x80), 0);
+        assert_eq!(char_starts(LSB * 0xBF), 0);
+        assert_eq!(char_starts(LSB * 0xC0), LSB);
+        assert_eq!(char_starts(LSB * 0x7F), LSB);
+    }
+}
@@ -0,0 +1,66 @@
+use core::fmt;
+
+/// The reason a fallible slice operation such as
+/// [`try_slice`](crate::try_slice) failed.
+///
+/// All indices and lengths are counted in the unit of the failed operation.
+/// For the top-level functions these are utf8/unicode characters, for the
+/// [`utf16`](crate::utf16) module these are UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SliceError {
+    /// The begin index lies after the end index
+    BeginAfterEnd {
+        /// The requested begin index
+        begin: usize,
+        /// The requested end index
+        end: usize,
+    },
+    /// The begin index lies past the end of the string
+    BeginOutOfBounds {
+        /// The requested begin index
+        begin: usize,
+        /// The length of the string
+        len: usize,
+    },
+    /// The end index lies past the end of the string
+    EndOutOfBounds {
+        /// The requested end index
+        end: usize,
+        /// The length of the string
+        len: usize,
+    },
+    /// The index lies between the two code units of a UTF-16 surrogate pair
+    ///
+    /// Only the [`utf16`](crate::utf16) module returns this variant.
+    SplitsSurrogatePair {
+        /// The requested index
+        index: usize,
+    },
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::BeginAfterEnd { begin, end } => {
+                write!(f, "slice index starts at {} but ends at {}", begin, end)
+            }
+            SliceError::BeginOutOfBounds { begin, len } => write!(
+                f,
+                "slice begin index {} is out of range for string of length {}",
+                begin, len
+            ),
+            SliceError::EndOutOfBounds { end, len } => write!(
+                f,
+                "slice end index {} is out of range for string of length {}",
+                end, len
+            ),
+            SliceError::SplitsSurrogatePair { index } => {
+                write!(f, "slice index {} lies within a surrogate pair", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceError {}
@@ -0,0 +1,433 @@
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+use core::ops::RangeBounds;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{Drain, String};
+
+use crate::{CharMatchIndices, Chunks, ChunksExact, Needle, RChunks, SliceError, Windows};
+
+/// Method syntax for the character indexed operations of this crate.
+///
+/// The trait is implemented for [`str`], which makes the methods available on
+/// everything that dereferences to a `str` such as `String` and `Cow<str>`.
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::Utf8SliceExt;
+///
+/// let s = String::from("The 🚀 goes to the 🌑!");
+///
+/// assert_eq!(s.char_slice(4, 5), "🚀");
+/// assert_eq!(s.char_len(), 20);
+///
+/// let rockets: Vec<&str> = vec!["🚀🚀", "🚀🚀🚀"]
+///     .into_iter()
+///     .map(|s| s.char_till(1))
+///     .collect();
+/// assert_eq!(rockets, ["🚀", "🚀"]);
+/// ```
+pub trait Utf8SliceExt {
+    /// Method version of [`slice`](crate::slice)
+    fn char_slice(&self, begin: usize, end: usize) -> &str;
+
+    /// Method version of [`from`](crate::from)
+    fn char_from(&self, begin: usize) -> &str;
+
+    /// Method version of [`till`](crate::till)
+    fn char_till(&self, end: usize) -> &str;
+
+    /// Method version of [`len`](crate::len)
+    fn char_len(&self) -> usize;
+
+    /// Method version of [`slice_range`](crate::slice_range)
+    fn char_slice_range(&self, range: impl RangeBounds<usize>) -> &str;
+
+    /// Method version of [`try_slice`](crate::try_slice)
+    fn try_char_slice(&self, begin: usize, end: usize) -> Result<&str, SliceError>;
+
+    /// Method version of [`try_from`](crate::try_from)
+    fn try_char_from(&self, begin: usize) -> Result<&str, SliceError>;
+
+    /// Method version of [`try_till`](crate::try_till)
+    fn try_char_till(&self, end: usize) -> Result<&str, SliceError>;
+
+    /// Method version of [`char_find`](crate::char_find)
+    fn char_find<N: Needle>(&self, needle: N) -> Option<usize>;
+
+    /// Method version of [`char_rfind`](crate::char_rfind)
+    fn char_rfind<N: Needle>(&self, needle: N) -> Option<usize>;
+
+    /// Method version of [`char_match_indices`](crate::char_match_indices)
+    fn char_match_indices<N: Needle>(&self, needle: N) -> CharMatchIndices<'_, N>;
+
+    /// Method version of [`char_split_at`](crate::char_split_at)
+    fn char_split_at(&self, char_idx: usize) -> Option<(&str, &str)>;
+
+    /// Method version of [`char_to_byte`](crate::char_to_byte)
+    fn char_to_byte(&self, char_idx: usize) -> Option<usize>;
+
+    /// Method version of [`byte_to_char`](crate::byte_to_char)
+    fn byte_to_char(&self, byte_idx: usize) -> Option<usize>;
+
+    /// Method version of [`chunks`](crate::chunks)
+    fn char_chunks(&self, n: usize) -> Chunks<'_>;
+
+    /// Method version of [`chunks_exact`](crate::chunks_exact)
+    fn char_chunks_exact(&self, n: usize) -> ChunksExact<'_>;
+
+    /// Method version of [`rchunks`](crate::rchunks)
+    fn char_rchunks(&self, n: usize) -> RChunks<'_>;
+
+    /// Method version of [`windows`](crate::windows)
+    fn char_windows(&self, n: usize) -> Windows<'_>;
+}
+
+impl Utf8SliceExt for str {
+    fn char_slice(&self, begin: usize, end: usize) -> &str {
+        crate::slice(self, begin, end)
+    }
+
+    fn char_from(&self, begin: usize) -> &str {
+        crate::from(self, begin)
+    }
+
+    fn char_till(&self, end: usize) -> &str {
+        crate::till(self, end)
+    }
+
+    fn char_len(&self) -> usize {
+        crate::len(self)
+    }
+
+    fn char_slice_range(&self, range: impl RangeBounds<usize>) -> &str {
+        crate::slice_range(self, range)
+    }
+
+    fn try_char_slice(&self, begin: usize, end: usize) -> Result<&str, SliceError> {
+        crate::try_slice(self, begin, end)
+    }
+
+    fn try_char_from(&self, begin: usize) -> Result<&str, SliceError> {
+        crate::try_from(self, begin)
+    }
+
+    fn try_char_till(&self, end: usize) -> Result<&str, SliceError> {
+        crate::try_till(self, end)
+    }
+
+    fn char_find<N: Needle>(&self, needle: N) -> Option<usize> {
+        crate::char_find(self, needle)
+    }
+
+    fn char_rfind<N: Needle>(&self, needle: N) -> Option<usize> {
+        crate::char_rfind(self, needle)
+    }
+
+    fn char_match_indices<N: Needle>(&self, needle: N) -> CharMatchIndices<'_, N> {
+        crate::char_match_indices(self, needle)
+    }
+
+    fn char_split_at(&self, char_idx: usize) -> Option<(&str, &str)> {
+        crate::char_split_at(self, char_idx)
+    }
+
+    fn char_to_byte(&self, char_idx: usize) -> Option<usize> {
+        crate::char_to_byte(self, char_idx)
+    }
+
+    fn byte_to_char(&self, byte_idx: usize) -> Option<usize> {
+        crate::byte_to_char(self, byte_idx)
+    }
+
+    fn char_chunks(&self, n: usize) -> Chunks<'_> {
+        crate::chunks(self, n)
+    }
+
+    fn char_chunks_exact(&self, n: usize) -> ChunksExact<'_> {
+        crate::chunks_exact(self, n)
+    }
+
+    fn char_rchunks(&self, n: usize) -> RChunks<'_> {
+        crate::rchunks(self, n)
+    }
+
+    fn char_windows(&self, n: usize) -> Windows<'_> {
+        crate::windows(self, n)
+    }
+}
+
+/// Character indexed editing of a [`String`].
+///
+/// Every method takes character indices with the same meaning as in
+/// [`slice`](crate::slice), and returns a [`SliceError`] instead of panicking
+/// when an index is out of range.
+///
+/// This trait is only available with the `alloc` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::StringExt;
+///
+/// let mut s = String::from("The 🚀 goes to the 🌑!");
+///
+/// s.char_replace_range(4..5, "🛸").unwrap();
+/// s.char_insert(19, '🌕').unwrap();
+/// assert_eq!(s, "The 🛸 goes to the 🌑🌕!");
+/// assert!(s.char_insert(22, '!').is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub trait StringExt {
+    /// Inserts a character at a character index
+    ///
+    /// The index may be equal to the length of the string to append.
+    fn char_insert(&mut self, idx: usize, ch: char) -> Result<(), SliceError>;
+
+    /// Inserts a string slice at a character index
+    ///
+    /// The index may be equal to the length of the string to append.
+    fn char_insert_str(&mut self, idx: usize, string: &str) -> Result<(), SliceError>;
+
+    /// Removes the character at a character index and returns it
+    fn char_remove(&mut self, idx: usize) -> Result<char, SliceError>;
+
+    /// Replaces a range of characters with a string slice
+    fn char_replace_range(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        replace_with: &str,
+    ) -> Result<(), SliceError>;
+
+    /// Shortens the string to `new_len` characters
+    fn char_truncate(&mut self, new_len: usize) -> Result<(), SliceError>;
+
+    /// Splits the string in two at a character index
+    ///
+    /// The string keeps the characters before `at`, the returned string
+    /// contains the characters from `at` onwards.
+    fn char_split_off(&mut self, at: usize) -> Result<String, SliceError>;
+
+    /// Removes a range of characters from the string and returns them as an
+    /// iterator
+    fn char_drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<'_>, SliceError>;
+}
+
+#[cfg(feature = "alloc")]
+impl StringExt for String {
+    fn char_insert(&mut self, idx: usize, ch: char) -> Result<(), SliceError> {
+        let pos = begin_pos(self, idx)?;
+        self.insert(pos, ch);
+        Ok(())
+    }
+
+    fn char_insert_str(&mut self, idx: usize, string: &str) -> Result<(), SliceError> {
+        let pos = begin_pos(self, idx)?;
+        self.insert_str(pos, string);
+        Ok(())
+    }
+
+    fn char_remove(&mut self, idx: usize) -> Result<char, SliceError> {
+        let pos = crate::char_to_byte(self, idx)
+            .filter(|&pos| pos < self.len())
+            .ok_or_else(|| SliceError::BeginOutOfBounds {
+                begin: idx,
+                len: crate::len(self),
+            })?;
+        Ok(self.remove(pos))
+    }
+
+    fn char_replace_range(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        replace_with: &str,
+    ) -> Result<(), SliceError> {
+        let range = byte_range(self, range)?;
+        self.replace_range(range, replace_with);
+        Ok(())
+    }
+
+    fn char_truncate(&mut self, new_len: usize) -> Result<(), SliceError> {
+        let pos = crate::char_to_byte(self, new_len).ok_or_else(|| SliceError::EndOutOfBounds {
+            end: new_len,
+            len: crate::len(self),
+        })?;
+        self.truncate(pos);
+        Ok(())
+    }
+
+    fn char_split_off(&mut self, at: usize) -> Result<String, SliceError> {
+        let pos = begin_pos(self, at)?;
+        Ok(self.split_off(pos))
+    }
+
+    fn char_drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<'_>, SliceError> {
+        let range = byte_range(self, range)?;
+        Ok(self.drain(range))
+    }
+}
+
+/// Converts a character index at which something starts into a byte position
+#[cfg(feature = "alloc")]
+fn begin_pos(s: &str, begin: usize) -> Result<usize, SliceError> {
+    crate::char_to_byte(s, begin).ok_or_else(|| SliceError::BeginOutOfBounds {
+        begin,
+        len: crate::len(s),
+    })
+}
+
+/// Converts a range of character indices into a range of byte positions
+#[cfg(feature = "alloc")]
+fn byte_range(s: &str, range: impl RangeBounds<usize>) -> Result<Range<usize>, SliceError> {
+    let (begin, end) = crate::range_indices(range);
+    let sliced = match end {
+        Some(end) => crate::try_slice(s, begin, end)?,
+        None => crate::try_from(s, begin)?,
+    };
+
+    let start_pos = sliced.as_ptr() as usize - s.as_ptr() as usize;
+    Ok(start_pos..start_pos + sliced.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::string::String;
+
+    #[test]
+    fn test_methods() {
+        let s = "\u{345}ab\u{898}xyz";
+        assert_eq!(s.char_slice(1, 4), "ab\u{898}");
+        assert_eq!(s.char_from(3), "\u{898}xyz");
+        assert_eq!(s.char_till(3), "\u{345}ab");
+        assert_eq!(s.char_len(), 7);
+        assert_eq!(s.char_slice_range(1..=3), "ab\u{898}");
+        assert_eq!(s.try_char_slice(1, 4), Ok("ab\u{898}"));
+        assert_eq!(s.try_char_from(7), Ok(""));
+        assert_eq!(
+            s.try_char_till(8),
+            Err(SliceError::EndOutOfBounds { end: 8, len: 7 })
+        );
+        assert_eq!(s.char_find('x'), Some(4));
+        assert_eq!(s.char_rfind(char::is_alphabetic), Some(6));
+        assert_eq!(s.char_match_indices("y").next(), Some((5, "y")));
+        assert_eq!(s.char_split_at(3), Some(("\u{345}ab", "\u{898}xyz")));
+        assert_eq!(s.char_to_byte(4), Some(7));
+        assert_eq!(s.char_to_byte(8), None);
+        assert_eq!(s.byte_to_char(7), Some(4));
+        assert_eq!(s.byte_to_char(1), None);
+        assert_eq!(s.char_chunks(3).nth(1), Some("\u{898}xy"));
+        assert_eq!(s.char_chunks_exact(3).count(), 2);
+        assert_eq!(s.char_rchunks(3).next(), Some("xyz"));
+        assert_eq!(s.char_windows(6).next_back(), Some("ab\u{898}xyz"));
+    }
+
+    #[test]
+    fn test_through_deref() {
+        let owned = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(owned.char_slice(1, 4), "ab\u{898}");
+
+        let cow: Cow<str> = Cow::Borrowed("\u{345}ab\u{898}xyz");
+        assert_eq!(cow.char_from(3), "\u{898}xyz");
+
+        let cow: Cow<str> = Cow::Owned(owned);
+        assert_eq!(cow.char_len(), 7);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_char_insert() {
+        let mut s = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(s.char_insert(1, '🚀'), Ok(()));
+        assert_eq!(s, "\u{345}🚀ab\u{898}xyz");
+        assert_eq!(s.char_insert(8, '!'), Ok(()));
+        assert_eq!(s, "\u{345}🚀ab\u{898}xyz!");
+        assert_eq!(
+            s.char_insert(10, '!'),
+            Err(SliceError::BeginOutOfBounds { begin: 10, len: 9 })
+        );
+
+        let mut s = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(s.char_insert_str(4, "🚀🌑"), Ok(()));
+        assert_eq!(s, "\u{345}ab\u{898}🚀🌑xyz");
+        assert_eq!(
+            s.char_insert_str(10, "!"),
+            Err(SliceError::BeginOutOfBounds { begin: 10, len: 9 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_char_remove() {
+        let mut s = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(s.char_remove(3), Ok('\u{898}'));
+        assert_eq!(s, "\u{345}abxyz");
+        assert_eq!(
+            s.char_remove(6),
+            Err(SliceError::BeginOutOfBounds { begin: 6, len: 6 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_char_replace_range() {
+        let mut s = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(s.char_replace_range(1..=3, "🚀"), Ok(()));
+        assert_eq!(s, "\u{345}🚀xyz");
+        assert_eq!(s.char_replace_range(2.., ""), Ok(()));
+        assert_eq!(s, "\u{345}🚀");
+        assert_eq!(
+            s.char_replace_range(1..3, ""),
+            Err(SliceError::EndOutOfBounds { end: 3, len: 2 })
+        );
+        assert_eq!(
+            s.char_replace_range(3.., ""),
+            Err(SliceError::BeginOutOfBounds { begin: 3, len: 2 })
+        );
+        assert_eq!(s, "\u{345}🚀");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_char_truncate() {
+        let mut s = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(s.char_truncate(7), Ok(()));
+        assert_eq!(s.char_truncate(4), Ok(()));
+        assert_eq!(s, "\u{345}ab\u{898}");
+        assert_eq!(
+            s.char_truncate(5),
+            Err(SliceError::EndOutOfBounds { end: 5, len: 4 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_char_split_off() {
+        let mut s = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(s.char_split_off(3), Ok(String::from("\u{898}xyz")));
+        assert_eq!(s, "\u{345}ab");
+        assert_eq!(s.char_split_off(3), Ok(String::new()));
+        assert_eq!(
+            s.char_split_off(4),
+            Err(SliceError::BeginOutOfBounds { begin: 4, len: 3 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_char_drain() {
+        use core::ops::Bound;
+
+        let mut s = String::from("\u{345}ab\u{898}xyz");
+        assert_eq!(s.char_drain(..3).unwrap().collect::<String>(), "\u{345}ab");
+        assert_eq!(s, "\u{898}xyz");
+        assert_eq!(
+            s.char_drain((Bound::Included(3), Bound::Excluded(2))).err(),
+            Some(SliceError::BeginAfterEnd { begin: 3, end: 2 })
+        );
+        assert_eq!(s.char_drain(..).unwrap().count(), 4);
+        assert_eq!(s, "");
+    }
+}
@@ -0,0 +1,316 @@
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::count;
+
+/// Something to search for with [`char_find`], [`char_rfind`] and
+/// [`char_match_indices`].
+///
+/// This mirrors the patterns accepted by [`str::find`] and is implemented for
+/// the same types: a [`char`], a string slice, a slice or array of `char`s
+/// matching any of them, and a closure `FnMut(char) -> bool` matching every
+/// character for which it returns `true`.
+pub trait Needle {
+    /// Fetches the byte range of the first match in `haystack`
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>>;
+
+    /// Fetches the byte range of the last match in `haystack`
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>>;
+}
+
+/// The byte range of the character starting at `pos`
+fn char_at(haystack: &str, pos: usize) -> Range<usize> {
+    pos..pos + haystack[pos..].chars().next().map_or(0, char::len_utf8)
+}
+
+impl Needle for char {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.find(*self).map(|pos| pos..pos + self.len_utf8())
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.rfind(*self).map(|pos| pos..pos + self.len_utf8())
+    }
+}
+
+impl Needle for &str {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.find(*self).map(|pos| pos..pos + self.len())
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.rfind(*self).map(|pos| pos..pos + self.len())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Needle for &String {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().rfind_in(haystack)
+    }
+}
+
+impl Needle for &[char] {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.find(*self).map(|pos| char_at(haystack, pos))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.rfind(*self).map(|pos| char_at(haystack, pos))
+    }
+}
+
+impl<const N: usize> Needle for [char; N] {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.find(*self).map(|pos| char_at(haystack, pos))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.rfind(*self).map(|pos| char_at(haystack, pos))
+    }
+}
+
+impl<F: FnMut(char) -> bool> Needle for F {
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.find(&mut *self).map(|pos| char_at(haystack, pos))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        haystack.rfind(&mut *self).map(|pos| char_at(haystack, pos))
+    }
+}
+
+/// Finds the character index of the first match of a needle
+/// taking into account utf8/unicode character indices.
+///
+/// # Arguments
+///
+/// * `s` - The string to search in
+/// * `needle` - What to search for
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::char_find(s, "goes"), Some(6));
+/// assert_eq!(utf8_slice::char_find(s, '🌑'), Some(18));
+/// assert_eq!(utf8_slice::char_find(s, char::is_whitespace), Some(3));
+/// assert_eq!(utf8_slice::char_find(s, 'x'), None);
+/// ```
+pub fn char_find<N: Needle>(s: &str, mut needle: N) -> Option<usize> {
+    needle
+        .find_in(s)
+        .map(|range| count::count_chars(&s[..range.start]))
+}
+
+/// Finds the character index of the last match of a needle
+/// taking into account utf8/unicode character indices.
+///
+/// # Arguments
+///
+/// * `s` - The string to search in
+/// * `needle` - What to search for
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::char_rfind(s, "the"), Some(14));
+/// assert_eq!(utf8_slice::char_rfind(s, ['T', 't']), Some(14));
+/// assert_eq!(utf8_slice::char_rfind(s, 'x'), None);
+/// ```
+pub fn char_rfind<N: Needle>(s: &str, mut needle: N) -> Option<usize> {
+    needle
+        .rfind_in(s)
+        .map(|range| count::count_chars(&s[..range.start]))
+}
+
+/// Iterates over the non-overlapping matches of a needle together with their
+/// character index
+///
+/// The characters are counted while searching, so every character is only
+/// counted once no matter how many matches there are. Just like
+/// [`str::match_indices`], an empty needle matches before every character
+/// and at the end of the string.
+///
+/// # Arguments
+///
+/// * `s` - The string to search in
+/// * `needle` - What to search for
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let matches: Vec<_> = utf8_slice::char_match_indices(s, "o").collect();
+/// assert_eq!(matches, [(7, "o"), (12, "o")]);
+/// ```
+pub fn char_match_indices<N: Needle>(s: &str, needle: N) -> CharMatchIndices<'_, N> {
+    CharMatchIndices {
+        s,
+        needle,
+        pos: 0,
+        char_pos: 0,
+        done: false,
+    }
+}
+
+/// Splits a string in two at a character index
+/// taking into account utf8/unicode character indices.
+///
+/// The index may be equal to the length of the string, which makes the second
+/// part empty.
+///
+/// # Arguments
+///
+/// * `s` - The string to split
+/// * `char_idx` - The character index at which the second part starts
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::char_split_at(s, 5), Some(("The 🚀", " goes to the 🌑!")));
+/// assert_eq!(utf8_slice::char_split_at(s, 21), None);
+/// ```
+///
+/// # Note
+/// * Will return `None` for out of range indices *
+pub fn char_split_at(s: &str, char_idx: usize) -> Option<(&str, &str)> {
+    count::char_pos(s, char_idx).map(|pos| s.split_at(pos))
+}
+
+/// An iterator over the matches of a needle and their character index
+///
+/// This struct is created by [`char_match_indices`].
+#[derive(Debug, Clone)]
+pub struct CharMatchIndices<'a, N> {
+    s: &'a str,
+    needle: N,
+    /// The byte position where the next search starts
+    pos: usize,
+    /// The character index of `pos`
+    char_pos: usize,
+    done: bool,
+}
+
+impl<'a, N: Needle> Iterator for CharMatchIndices<'a, N> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let rest = &self.s[self.pos..];
+        let range = match self.needle.find_in(rest) {
+            Some(range) => range,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+
+        let char_idx = self.char_pos + count::count_chars(&rest[..range.start]);
+        let matched = &rest[range.clone()];
+
+        if matched.is_empty() {
+            // Step over a character, otherwise the same empty match would be
+            // found again
+            match rest[range.start..].chars().next() {
+                Some(c) => {
+                    self.pos += range.start + c.len_utf8();
+                    self.char_pos = char_idx + 1;
+                }
+                None => self.done = true,
+            }
+        } else {
+            self.pos += range.end;
+            self.char_pos = char_idx + count::count_chars(matched);
+        }
+
+        Some((char_idx, matched))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_char_find() {
+        let s = "\u{345}ab\u{898}xyz\u{898}";
+        assert_eq!(char_find(s, 'a'), Some(1));
+        assert_eq!(char_find(s, '\u{898}'), Some(3));
+        assert_eq!(char_find(s, "\u{898}x"), Some(3));
+        assert_eq!(char_find(s, ""), Some(0));
+        assert_eq!(char_find(s, &['z', 'y'][..]), Some(5));
+        assert_eq!(char_find(s, |c: char| !c.is_ascii()), Some(0));
+        assert_eq!(char_find(s, 'q'), None);
+    }
+
+    #[test]
+    fn test_char_rfind() {
+        let s = "\u{345}ab\u{898}xyz\u{898}";
+        assert_eq!(char_rfind(s, '\u{898}'), Some(7));
+        assert_eq!(char_rfind(s, ""), Some(8));
+        assert_eq!(char_rfind(s, ['a', 'x']), Some(4));
+        assert_eq!(char_rfind(s, |c: char| c.is_ascii()), Some(6));
+        assert_eq!(char_rfind(s, "q"), None);
+    }
+
+    #[test]
+    fn test_char_match_indices() {
+        let s = "🚀a🚀🚀b🚀";
+        let matches: Vec<_> = char_match_indices(s, '🚀').collect();
+        assert_eq!(matches, [(0, "🚀"), (2, "🚀"), (3, "🚀"), (5, "🚀")]);
+
+        let matches: Vec<_> = char_match_indices(s, "🚀🚀").collect();
+        assert_eq!(matches, [(2, "🚀🚀")]);
+
+        let matches: Vec<_> = char_match_indices(s, char::is_alphabetic).collect();
+        assert_eq!(matches, [(1, "a"), (4, "b")]);
+
+        assert_eq!(char_match_indices(s, 'x').next(), None);
+    }
+
+    #[test]
+    fn test_same_as_match_indices() {
+        let s = "aaa\u{898}aa\u{345}a";
+        for needle in ["", "a", "aa", "\u{898}", "a\u{345}"].iter() {
+            let expected: Vec<_> = s
+                .match_indices(needle)
+                .map(|(pos, m)| (crate::len(&s[..pos]), m))
+                .collect();
+            let actual: Vec<_> = char_match_indices(s, *needle).collect();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_char_split_at() {
+        let s = "\u{345}ab\u{898}";
+        assert_eq!(char_split_at(s, 0), Some(("", s)));
+        assert_eq!(char_split_at(s, 1), Some(("\u{345}", "ab\u{898}")));
+        assert_eq!(char_split_at(s, 4), Some((s, "")));
+        assert_eq!(char_split_at(s, 5), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_string_needle() {
+        let needle = std::string::String::from("b\u{898}");
+        assert_eq!(char_find("\u{345}ab\u{898}", &needle), Some(2));
+    }
+}
@@ -0,0 +1,168 @@
+//! Grapheme cluster aware versions of the slice utilities.
+//!
+//! Where the top-level functions count unicode scalar values, the functions in
+//! this module count extended grapheme clusters as defined by
+//! [UAX #29](https://www.unicode.org/reports/tr29/). This keeps user-perceived
+//! characters such as `"👨‍🚀"` or `"e\u{301}"` together.
+//!
+//! This module is only available with the `grapheme` feature enabled.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Fetches a slice of a string from a begin to an end index
+/// taking into account grapheme cluster indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 👨‍🚀 goes to the 🌑!";
+///
+/// let astronaut = utf8_slice::grapheme::slice(s, 4, 5);
+/// # assert_eq!(utf8_slice::grapheme::slice(s, 4, 5), "👨‍🚀");
+/// // Will equal "👨‍🚀"
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn slice(s: &str, begin: usize, end: usize) -> &str {
+    if end <= begin {
+        return "";
+    }
+
+    let mut boundaries = s
+        .grapheme_indices(true)
+        .map(|(pos, _)| pos)
+        .chain(Some(s.len()));
+
+    boundaries
+        .nth(begin)
+        .filter(|&start_pos| start_pos < s.len())
+        .map(|start_pos| {
+            let end_pos = boundaries.nth(end - begin - 1).unwrap_or(s.len());
+            &s[start_pos..end_pos]
+        })
+        .unwrap_or("")
+}
+
+/// Fetches a slice of a string from a starting index
+/// taking into account grapheme cluster indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 👨‍🚀 goes to the 🌑!";
+///
+/// let astronaut_goes_to_the_moon = utf8_slice::grapheme::from(s, 4);
+/// # assert_eq!(utf8_slice::grapheme::from(s, 4), "👨‍🚀 goes to the 🌑!");
+/// // Will equal "👨‍🚀 goes to the 🌑!"
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn from(s: &str, begin: usize) -> &str {
+    s.grapheme_indices(true)
+        .nth(begin)
+        .map(|(start_pos, _)| &s[start_pos..])
+        .unwrap_or("")
+}
+
+/// Fetches a slice of a string until an ending index
+/// taking into account grapheme cluster indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 👨‍🚀 goes to the 🌑!";
+///
+/// let the_astronaut = utf8_slice::grapheme::till(s, 5);
+/// # assert_eq!(utf8_slice::grapheme::till(s, 5), "The 👨‍🚀");
+/// // Will equal "The 👨‍🚀"
+/// ```
+pub fn till(s: &str, end: usize) -> &str {
+    s.grapheme_indices(true)
+        .nth(end)
+        .map(|(end_pos, _)| &s[..end_pos])
+        .unwrap_or(s)
+}
+
+/// Fetches the length in grapheme clusters of an utf8/unicode string
+///
+/// # Arguments
+///
+/// * `s` - The string of which to fetch the length
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(utf8_slice::grapheme::len("👨‍🚀"), 1);
+/// assert_eq!(utf8_slice::len("👨‍🚀"), 3);
+/// ```
+pub fn len(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_as_char_slice_for_ascii() {
+        let s = "xjfdlskfaj sdfjlkj";
+        for i in 0..s.len() + 2 {
+            for j in 0..s.len() + 2 {
+                assert_eq!(crate::slice(s, i, j), slice(s, i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        assert_eq!(slice("a👨‍🚀b", 1, 2), "👨‍🚀");
+        assert_eq!(slice("a👨‍🚀b", 0, 3), "a👨‍🚀b");
+        assert_eq!(slice("a👨‍🚀b", 1, 10), "👨‍🚀b");
+        assert_eq!(slice("e\u{301}x", 0, 1), "e\u{301}");
+        assert_eq!(slice("a👨‍🚀b", 2, 1), "");
+        assert_eq!(slice("a👨‍🚀b", 3, 4), "");
+        assert_eq!(slice("a👨‍🚀b", 2, 2), "");
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(from("a👨‍🚀b", 1), "👨‍🚀b");
+        assert_eq!(from("a👨‍🚀b", 2), "b");
+        assert_eq!(from("a👨‍🚀b", 3), "");
+        assert_eq!(from("a👨‍🚀b", 0), "a👨‍🚀b");
+    }
+
+    #[test]
+    fn test_till() {
+        assert_eq!(till("a👨‍🚀b", 2), "a👨‍🚀");
+        assert_eq!(till("e\u{301}\u{302}x", 1), "e\u{301}\u{302}");
+        assert_eq!(till("a👨‍🚀b", 0), "");
+        assert_eq!(till("a👨‍🚀b", 10), "a👨‍🚀b");
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(len(""), 0);
+        assert_eq!(len("👨‍🚀"), 1);
+        assert_eq!(len("abd👨‍🚀"), 4);
+        assert_eq!(len("e\u{301}\r\n"), 2);
+    }
+}
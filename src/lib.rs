@@ -28,6 +28,58 @@
 //* // Will equal "🚀"
 //* ```
 
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+pub mod bytes;
+#[cfg(feature = "alloc")]
+mod char_index;
+mod chunks;
+pub mod const_fn;
+mod count;
+mod error;
+mod ext;
+mod find;
+#[cfg(feature = "grapheme")]
+pub mod grapheme;
+pub mod lines;
+#[cfg(feature = "alloc")]
+pub mod lsp;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "sentences")]
+pub mod sentences;
+pub mod signed;
+pub mod sms;
+#[cfg(feature = "alloc")]
+mod truncate;
+pub mod utf16;
+#[cfg(feature = "width")]
+pub mod width;
+#[cfg(feature = "words")]
+pub mod words;
+
+#[cfg(feature = "alloc")]
+pub use char_index::CharIndex;
+pub use chunks::{chunks, chunks_exact, rchunks, windows, Chunks, ChunksExact, RChunks, Windows};
+pub use error::SliceError;
+#[cfg(feature = "alloc")]
+pub use ext::StringExt;
+pub use ext::Utf8SliceExt;
+pub use find::{
+    char_find, char_match_indices, char_rfind, char_split_at, CharMatchIndices, Needle,
+};
+#[cfg(feature = "std")]
+pub use reader::CharReader;
+#[cfg(feature = "alloc")]
+pub use truncate::{truncate_with, truncate_words_with, Unit};
+
+use core::ops::{Bound, RangeBounds};
+
 /// Fetches a slice of a string from a begin to an end index
 /// taking into account utf8/unicode character indices.
 ///
@@ -50,23 +102,26 @@
 /// # Note
 /// * Will return an empty string for invalid indices *
 pub fn slice(s: &str, begin: usize, end: usize) -> &str {
-    if end < begin {
+    if end <= begin {
         return "";
     }
 
-    s.char_indices()
-        .nth(begin)
-        .and_then(|(start_pos, _)| {
-            if end >= len(s) {
-                return Some(&s[start_pos..]);
-            }
+    // Character and byte indices are the same for as long as the string is
+    // ASCII.
+    let prefix = &s.as_bytes()[..end.min(s.len())];
+    if prefix.is_ascii() {
+        return s.get(begin..prefix.len()).unwrap_or("");
+    }
 
-            s[start_pos..]
-                .char_indices()
-                .nth(end - begin)
-                .map(|(end_pos, _)| &s[start_pos..start_pos + end_pos])
-        })
-        .unwrap_or("")
+    let start_pos = match count::char_pos(s, begin) {
+        Some(start_pos) if start_pos < s.len() => start_pos,
+        _ => return "",
+    };
+    let end_pos = count::char_pos(&s[start_pos..], end - begin)
+        .map(|end_pos| start_pos + end_pos)
+        .unwrap_or(s.len());
+
+    &s[start_pos..end_pos]
 }
 
 /// Fetches a slice of a string from a starting index
@@ -90,7 +145,12 @@ pub fn slice(s: &str, begin: usize, end: usize) -> &str {
 /// # Note
 /// * Will return an empty string for invalid indices *
 pub fn from(s: &str, begin: usize) -> &str {
-    slice(s, begin, len(s))
+    // Only the characters before `begin` need to be walked, everything after
+    // it is part of the slice.
+    match count::char_pos(s, begin) {
+        Some(start_pos) if start_pos < s.len() => &s[start_pos..],
+        _ => "",
+    }
 }
 
 /// Fetches a slice of a string until an ending index
@@ -107,7 +167,7 @@ pub fn from(s: &str, begin: usize) -> &str {
 /// let s = "The 🚀 goes to the 🌑!";
 ///
 /// let the_rocket = utf8_slice::till(s, 5);
-/// # assert_eq!(utf8_slice::till(s, 4), "The 🚀");
+/// # assert_eq!(utf8_slice::till(s, 5), "The 🚀");
 /// // Will equal "The 🚀"
 /// ```
 ///
@@ -117,13 +177,248 @@ pub fn till(s: &str, end: usize) -> &str {
     slice(s, 0, end)
 }
 
+/// Fetches a slice of a string for a range of indices
+/// taking into account utf8/unicode character indices.
+///
+/// This accepts all of the standard range types and behaves the same as
+/// [`slice`](fn@slice), [`from`] or [`till`] for the equivalent begin and end indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `range` - The range of character indices to take
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::slice_range;
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(slice_range(s, 4..5), "🚀");
+/// assert_eq!(slice_range(s, 4..=4), "🚀");
+/// assert_eq!(slice_range(s, 18..), "🌑!");
+/// assert_eq!(slice_range(s, ..5), "The 🚀");
+/// assert_eq!(slice_range(s, ..=4), "The 🚀");
+/// assert_eq!(slice_range(s, ..), s);
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn slice_range(s: &str, range: impl RangeBounds<usize>) -> &str {
+    let (begin, end) = range_indices(range);
+    slice(s, begin, end.unwrap_or(usize::MAX))
+}
+
+/// Converts a range into a begin index and an optional end index, where `None`
+/// means the range is unbounded at the end.
+pub(crate) fn range_indices(range: impl RangeBounds<usize>) -> (usize, Option<usize>) {
+    let begin = match range.start_bound() {
+        Bound::Included(&begin) => begin,
+        Bound::Excluded(&begin) => begin.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => Some(end.saturating_add(1)),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => None,
+    };
+
+    (begin, end)
+}
+
+/// Fetches a slice of a string from a begin to an end index
+/// taking into account utf8/unicode character indices.
+///
+/// Unlike [`slice`](fn@slice), an invalid index is reported as an error instead of
+/// resulting in an empty string.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::SliceError;
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::try_slice(s, 4, 5), Ok("🚀"));
+/// assert_eq!(
+///     utf8_slice::try_slice(s, 4, 25),
+///     Err(SliceError::EndOutOfBounds { end: 25, len: 20 })
+/// );
+/// ```
+pub fn try_slice(s: &str, begin: usize, end: usize) -> Result<&str, SliceError> {
+    if end < begin {
+        return Err(SliceError::BeginAfterEnd { begin, end });
+    }
+
+    let start_pos = count::char_pos(s, begin)
+        .ok_or_else(|| SliceError::BeginOutOfBounds { begin, len: len(s) })?;
+
+    count::char_pos(&s[start_pos..], end - begin)
+        .map(|end_pos| &s[start_pos..start_pos + end_pos])
+        .ok_or_else(|| SliceError::EndOutOfBounds { end, len: len(s) })
+}
+
+/// Fetches a slice of a string from a starting index
+/// taking into account utf8/unicode character indices.
+///
+/// Unlike [`from`], an invalid index is reported as an error instead of
+/// resulting in an empty string.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::SliceError;
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::try_from(s, 18), Ok("🌑!"));
+/// assert_eq!(utf8_slice::try_from(s, 20), Ok(""));
+/// assert_eq!(
+///     utf8_slice::try_from(s, 21),
+///     Err(SliceError::BeginOutOfBounds { begin: 21, len: 20 })
+/// );
+/// ```
+pub fn try_from(s: &str, begin: usize) -> Result<&str, SliceError> {
+    count::char_pos(s, begin)
+        .map(|start_pos| &s[start_pos..])
+        .ok_or_else(|| SliceError::BeginOutOfBounds { begin, len: len(s) })
+}
+
+/// Fetches a slice of a string until an ending index
+/// taking into account utf8/unicode character indices.
+///
+/// Unlike [`till`], an invalid index is reported as an error instead of
+/// being clamped to the length of the string.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::SliceError;
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::try_till(s, 5), Ok("The 🚀"));
+/// assert_eq!(
+///     utf8_slice::try_till(s, 21),
+///     Err(SliceError::EndOutOfBounds { end: 21, len: 20 })
+/// );
+/// ```
+pub fn try_till(s: &str, end: usize) -> Result<&str, SliceError> {
+    count::char_pos(s, end)
+        .map(|end_pos| &s[..end_pos])
+        .ok_or_else(|| SliceError::EndOutOfBounds { end, len: len(s) })
+}
+
+/// Converts a character index into the byte position of that character
+/// taking into account utf8/unicode character indices.
+///
+/// The length of the string in characters is a valid index and maps to the
+/// length of the string in bytes.
+///
+/// # Arguments
+///
+/// * `s` - The string in which to look up the character
+/// * `char_idx` - The character index to convert
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::char_to_byte(s, 5), Some(8));
+/// assert_eq!(utf8_slice::char_to_byte(s, 20), Some(s.len()));
+/// assert_eq!(utf8_slice::char_to_byte(s, 21), None);
+/// ```
+pub fn char_to_byte(s: &str, char_idx: usize) -> Option<usize> {
+    count::char_pos(s, char_idx)
+}
+
+/// Converts a byte position into the index of the character starting at that
+/// position taking into account utf8/unicode character indices.
+///
+/// The length of the string in bytes is a valid position and maps to the
+/// length of the string in characters.
+///
+/// # Arguments
+///
+/// * `s` - The string in which to look up the byte position
+/// * `byte_idx` - The byte position to convert
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::byte_to_char(s, 8), Some(5));
+/// assert_eq!(utf8_slice::byte_to_char(s, s.len()), Some(20));
+/// // Byte 5 lies within the 🚀
+/// assert_eq!(utf8_slice::byte_to_char(s, 5), None);
+/// ```
+///
+/// # Note
+/// * Will return `None` for positions which are not on a character boundary *
+pub fn byte_to_char(s: &str, byte_idx: usize) -> Option<usize> {
+    if !s.is_char_boundary(byte_idx) {
+        return None;
+    }
+
+    Some(len(&s[..byte_idx]))
+}
+
 /// Fetches the length in characters of an utf8/unicode string
 ///
 /// # Arguments
 ///
 /// * `s` - The string of which to fetch the length
 pub fn len(s: &str) -> usize {
-    s.chars().count()
+    count::count_chars(s)
+}
+
+/// Fixtures shared by the tests of the modules which reimplement slicing
+#[cfg(test)]
+pub(crate) mod test_util {
+    /// Strings covering the empty string, ascii and multi byte characters
+    pub(crate) const STRINGS: [&str; 4] = [
+        "",
+        "xjfdlskfaj sdfjlkj",
+        "\u{345}ab\u{898}xyz",
+        "The 🚀 goes to the 🌑! 👨‍🚀 ö",
+    ];
+
+    /// Asserts that another implementation of `slice`, `from` and `till`
+    /// agrees with the ones of the crate root for every pair of indices up to
+    /// past the end of `s`
+    pub(crate) fn assert_same_as_slice<'a>(
+        s: &str,
+        slice: impl Fn(usize, usize) -> &'a str,
+        from: impl Fn(usize) -> &'a str,
+        till: impl Fn(usize) -> &'a str,
+    ) {
+        for i in 0..s.len() + 2 {
+            for j in 0..s.len() + 2 {
+                assert_eq!(slice(i, j), crate::slice(s, i, j));
+            }
+            assert_eq!(from(i), crate::from(s, i));
+            assert_eq!(till(i), crate::till(s, i));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -140,6 +435,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_same_as_char_indices() {
+        let strings = ["", "abc", "ab\u{898}xyz", "\u{345}ab\u{898}xyz", "🚀🌑"];
+        for s in strings.iter() {
+            let chars: std::vec::Vec<usize> = s
+                .char_indices()
+                .map(|(pos, _)| pos)
+                .chain(Some(s.len()))
+                .collect();
+            for i in 0..chars.len() + 2 {
+                for j in 0..chars.len() + 2 {
+                    let expected = match (chars.get(i), chars.get(j.min(chars.len() - 1))) {
+                        (Some(&start), Some(&end)) if i < j && start < s.len() => &s[start..end],
+                        _ => "",
+                    };
+                    assert_eq!(slice(s, i, j), expected);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_slice() {
         assert_eq!(slice("\u{345}ab\u{898}xyz", 1, 4), "ab\u{898}");
@@ -165,6 +481,91 @@ mod tests {
         assert_eq!(till("\u{345}ab\u{898}xyz", 0), "");
     }
 
+    #[test]
+    fn test_slice_range() {
+        let s = "\u{345}ab\u{898}xyz";
+        for i in 0..10 {
+            for j in 0..10 {
+                assert_eq!(slice_range(s, i..j), slice(s, i, j));
+                assert_eq!(slice_range(s, i..=j), slice(s, i, j + 1));
+                assert_eq!(
+                    slice_range(s, (Bound::Excluded(i), Bound::Excluded(j))),
+                    slice(s, i + 1, j)
+                );
+            }
+            assert_eq!(slice_range(s, i..), from(s, i));
+            assert_eq!(slice_range(s, ..i), till(s, i));
+            assert_eq!(slice_range(s, ..=i), till(s, i + 1));
+        }
+        assert_eq!(slice_range(s, ..), s);
+        assert_eq!(slice_range(s, 2..=usize::MAX), "b\u{898}xyz");
+        assert_eq!(slice_range("", ..), "");
+    }
+
+    #[test]
+    fn test_try_slice() {
+        assert_eq!(try_slice("\u{345}ab\u{898}xyz", 1, 4), Ok("ab\u{898}"));
+        assert_eq!(try_slice("\u{345}ab\u{898}xyz", 7, 7), Ok(""));
+        assert_eq!(
+            try_slice("\u{345}ab\u{898}xyz", 0, 7),
+            Ok("\u{345}ab\u{898}xyz")
+        );
+        assert_eq!(
+            try_slice("\u{345}ab\u{898}xyz", 5, 4),
+            Err(SliceError::BeginAfterEnd { begin: 5, end: 4 })
+        );
+        assert_eq!(
+            try_slice("\u{345}ab\u{898}xyz", 8, 9),
+            Err(SliceError::BeginOutOfBounds { begin: 8, len: 7 })
+        );
+        assert_eq!(
+            try_slice("\u{345}ab\u{898}xyz", 1, 8),
+            Err(SliceError::EndOutOfBounds { end: 8, len: 7 })
+        );
+        assert_eq!(try_slice("", 0, 0), Ok(""));
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(try_from("\u{345}ab\u{898}xyz", 3), Ok("\u{898}xyz"));
+        assert_eq!(try_from("\u{345}ab\u{898}xyz", 7), Ok(""));
+        assert_eq!(
+            try_from("\u{345}ab\u{898}xyz", 10),
+            Err(SliceError::BeginOutOfBounds { begin: 10, len: 7 })
+        );
+    }
+
+    #[test]
+    fn test_try_till() {
+        assert_eq!(try_till("\u{345}ab\u{898}xyz", 3), Ok("\u{345}ab"));
+        assert_eq!(try_till("\u{345}ab\u{898}xyz", 0), Ok(""));
+        assert_eq!(
+            try_till("\u{345}ab\u{898}xyz", 8),
+            Err(SliceError::EndOutOfBounds { end: 8, len: 7 })
+        );
+    }
+
+    #[test]
+    fn test_char_to_byte() {
+        assert_eq!(char_to_byte("\u{345}ab\u{898}xyz", 0), Some(0));
+        assert_eq!(char_to_byte("\u{345}ab\u{898}xyz", 1), Some(2));
+        assert_eq!(char_to_byte("\u{345}ab\u{898}xyz", 4), Some(7));
+        assert_eq!(char_to_byte("\u{345}ab\u{898}xyz", 7), Some(10));
+        assert_eq!(char_to_byte("\u{345}ab\u{898}xyz", 8), None);
+        assert_eq!(char_to_byte("", 0), Some(0));
+    }
+
+    #[test]
+    fn test_byte_to_char() {
+        let s = "\u{345}ab\u{898}xyz";
+        for i in 0..=len(s) {
+            assert_eq!(char_to_byte(s, i).and_then(|b| byte_to_char(s, b)), Some(i));
+        }
+        assert_eq!(byte_to_char(s, 1), None);
+        assert_eq!(byte_to_char(s, 5), None);
+        assert_eq!(byte_to_char(s, 11), None);
+    }
+
     #[test]
     fn test_len() {
         assert_eq!(len(""), 0);
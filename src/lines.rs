@@ -0,0 +1,486 @@
+//! Conversions between character offsets and line/column positions.
+//!
+//! Lines are separated by `"\n"`, `"\r\n"` or a lone `"\r"`. A line ending
+//! belongs to the line it ends, but is not part of the text returned for that
+//! line. A string which ends with a line ending has an empty last line, so
+//! `"a\n"` consists of the lines `"a"` and `""`.
+//!
+//! Columns are counted in utf8/unicode characters, the same way as the
+//! top-level functions of this crate count them.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Fetches the lines of a string from a begin to an end line, counted from 0
+///
+/// The result runs from the start of line `start` until the start of line
+/// `end`, so it includes the line ending of every line in it.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the lines from
+/// * `start` - The first line to include
+/// * `end` - The line after the last line to include
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::lines;
+///
+/// let s = "The 🚀\ngoes to\r\nthe 🌑!";
+///
+/// assert_eq!(lines::slice_lines(s, 1, 2), "goes to\r\n");
+/// assert_eq!(lines::slice_lines(s, 1, 5), "goes to\r\nthe 🌑!");
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn slice_lines(s: &str, start: usize, end: usize) -> &str {
+    if end <= start {
+        return "";
+    }
+
+    let start_pos = match start.checked_sub(1) {
+        None => 0,
+        Some(n) => match line_breaks(s).nth(n) {
+            Some((_, next)) => next,
+            None => return "",
+        },
+    };
+    let end_pos = line_breaks(&s[start_pos..])
+        .nth(end - start - 1)
+        .map_or(s.len(), |(_, next)| start_pos + next);
+
+    &s[start_pos..end_pos]
+}
+
+/// Fetches a slice of a single line from a begin to an end column, counted
+/// from 0
+///
+/// The line ending is not part of the line, so it can never be sliced.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the line from
+/// * `line` - The line to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::lines;
+///
+/// let s = "The 🚀\ngoes to\r\nthe 🌑!";
+///
+/// assert_eq!(lines::slice_columns(s, 0, 4, 5), "🚀");
+/// assert_eq!(lines::slice_columns(s, 2, 4, 10), "🌑!");
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn slice_columns(s: &str, line: usize, begin: usize, end: usize) -> &str {
+    let text = match line.checked_sub(1) {
+        None => s,
+        Some(n) => match line_breaks(s).nth(n) {
+            Some((_, next)) => &s[next..],
+            None => return "",
+        },
+    };
+    let text = match line_breaks(text).next() {
+        Some((terminator, _)) => &text[..terminator],
+        None => text,
+    };
+
+    crate::slice(text, begin, end)
+}
+
+/// Yields the byte position of every line ending together with the byte
+/// position of the line after it
+fn line_breaks(s: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+
+    core::iter::from_fn(move || {
+        let terminator = pos
+            + bytes[pos..]
+                .iter()
+                .position(|&b| b == b'\n' || b == b'\r')?;
+        pos = if bytes[terminator..].starts_with(b"\r\n") {
+            terminator + 2
+        } else {
+            terminator + 1
+        };
+        Some((terminator, pos))
+    })
+}
+
+/// Whether the lines and columns of a [`LineIndex`] are counted from 0 or 1
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base {
+    /// The first line and column are 0, as used by most APIs
+    Zero,
+    /// The first line and column are 1, as used by most compiler diagnostics
+    /// and editors
+    One,
+}
+
+#[cfg(feature = "alloc")]
+impl Base {
+    fn to_zero(self, n: usize) -> Option<usize> {
+        match self {
+            Base::Zero => Some(n),
+            Base::One => n.checked_sub(1),
+        }
+    }
+
+    fn to_base(self, n: usize) -> usize {
+        match self {
+            Base::Zero => n,
+            Base::One => n + 1,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy)]
+struct LineStart {
+    byte: usize,
+    char: usize,
+}
+
+/// A precomputed index over the lines of a string, which converts between
+/// character offsets and `(line, column)` positions.
+///
+/// The index stores where every line starts, so conversions only need a
+/// binary search over the lines.
+///
+/// This type is only available with the `alloc` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::lines::{Base, LineIndex};
+///
+/// let s = "The 🚀\ngoes to\r\nthe 🌑!";
+/// let index = LineIndex::with_base(s, Base::One);
+///
+/// assert_eq!(index.position(19), Some((3, 5)));
+/// assert_eq!(index.offset(3, 5), Some(19));
+/// assert_eq!(utf8_slice::slice(s, 19, 20), "🌑");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct LineIndex<'a> {
+    s: &'a str,
+    base: Base,
+    starts: Vec<LineStart>,
+    len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> LineIndex<'a> {
+    /// Builds an index over `s` which counts lines and columns from 0
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to index
+    pub fn new(s: &'a str) -> Self {
+        Self::with_base(s, Base::Zero)
+    }
+
+    /// Builds an index over `s` which counts lines and columns from `base`
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to index
+    /// * `base` - Whether lines and columns are counted from 0 or 1
+    pub fn with_base(s: &'a str, base: Base) -> Self {
+        let mut starts = Vec::new();
+        let mut last = LineStart { byte: 0, char: 0 };
+        starts.push(last);
+
+        for (_, next) in line_breaks(s) {
+            last = LineStart {
+                byte: next,
+                char: last.char + crate::len(&s[last.byte..next]),
+            };
+            starts.push(last);
+        }
+
+        LineIndex {
+            s,
+            base,
+            starts,
+            len: last.char + crate::len(&s[last.byte..]),
+        }
+    }
+
+    /// Returns the indexed string
+    pub fn as_str(&self) -> &'a str {
+        self.s
+    }
+
+    /// Returns whether lines and columns are counted from 0 or 1
+    pub fn base(&self) -> Base {
+        self.base
+    }
+
+    /// Fetches the amount of lines in the indexed string
+    ///
+    /// This is always at least 1, since an empty string consists of a single
+    /// empty line.
+    pub fn line_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Fetches the text of a line without its line ending
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line to fetch
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let index = utf8_slice::lines::LineIndex::new("The 🚀\ngoes to\r\nthe 🌑!");
+    ///
+    /// assert_eq!(index.line(1), Some("goes to"));
+    /// assert_eq!(index.line(3), None);
+    /// ```
+    pub fn line(&self, line: usize) -> Option<&'a str> {
+        let line = self.base.to_zero(line)?;
+        let start = self.starts.get(line)?.byte;
+
+        Some(match self.starts.get(line + 1) {
+            Some(next) => {
+                let text = &self.s[start..next.byte];
+                text.strip_suffix("\r\n")
+                    .or_else(|| text.strip_suffix(&['\n', '\r'][..]))
+                    .unwrap_or(text)
+            }
+            None => &self.s[start..],
+        })
+    }
+
+    /// Converts a character offset into a `(line, column)` position
+    ///
+    /// The length of the string is a valid offset and maps to the end of the
+    /// last line. An offset which points at a line ending maps to the columns
+    /// after the end of the line's text.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The character offset to convert
+    pub fn position(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.len {
+            return None;
+        }
+
+        let line = self.starts.partition_point(|start| start.char <= offset) - 1;
+        let column = offset - self.starts[line].char;
+
+        Some((self.base.to_base(line), self.base.to_base(column)))
+    }
+
+    /// Converts a `(line, column)` position into a character offset
+    ///
+    /// This is the inverse of [`LineIndex::position`], so the column may
+    /// point at the line ending, but not past it.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line of the position
+    /// * `column` - The column of the position
+    pub fn offset(&self, line: usize, column: usize) -> Option<usize> {
+        let line = self.base.to_zero(line)?;
+        let column = self.base.to_zero(column)?;
+        let start = self.starts.get(line)?.char;
+        let end = self
+            .starts
+            .get(line + 1)
+            .map_or(self.len + 1, |next| next.char);
+
+        Some(start + column).filter(|&offset| offset < end)
+    }
+
+    /// Fetches the byte position where a line starts, counted from 0
+    pub(crate) fn line_start(&self, line: usize) -> Option<usize> {
+        self.starts.get(line).map(|start| start.byte)
+    }
+
+    /// Fetches the line, counted from 0, which contains a byte position
+    pub(crate) fn line_at(&self, byte: usize) -> usize {
+        self.starts.partition_point(|start| start.byte <= byte) - 1
+    }
+
+    /// Fetches the lines of the indexed string from a begin to an end line
+    ///
+    /// This behaves the same as [`slice_lines`].
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first line to include
+    /// * `end` - The line after the last line to include
+    ///
+    /// # Note
+    /// * Will return an empty string for invalid indices *
+    pub fn slice_lines(&self, start: usize, end: usize) -> &'a str {
+        let (start, end) = match (self.base.to_zero(start), self.base.to_zero(end)) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return "",
+        };
+        if end <= start || start >= self.starts.len() {
+            return "";
+        }
+
+        let start_pos = self.starts[start].byte;
+        let end_pos = self.starts.get(end).map_or(self.s.len(), |next| next.byte);
+        &self.s[start_pos..end_pos]
+    }
+
+    /// Fetches a slice of a single line from a begin to an end column
+    ///
+    /// This behaves the same as [`slice_columns`].
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line to take the slice from
+    /// * `begin` - Where the slice begins
+    /// * `end` - Where the slice ends
+    ///
+    /// # Note
+    /// * Will return an empty string for invalid indices *
+    pub fn slice_columns(&self, line: usize, begin: usize, end: usize) -> &'a str {
+        match (
+            self.line(line),
+            self.base.to_zero(begin),
+            self.base.to_zero(end),
+        ) {
+            (Some(text), Some(begin), Some(end)) => crate::slice(text, begin, end),
+            _ => "",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    const STRINGS: [&str; 6] = [
+        "",
+        "\n",
+        "abc",
+        "a\nb\r\nc\rd",
+        "\r\r\n\n\u{345}ab\u{898}\r\nxyz\n",
+        "The 🚀\ngoes to\r\nthe 🌑!",
+    ];
+
+    #[test]
+    fn test_slice_lines() {
+        let s = "a\nb\r\nc\rd";
+        assert_eq!(slice_lines(s, 0, 1), "a\n");
+        assert_eq!(slice_lines(s, 1, 3), "b\r\nc\r");
+        assert_eq!(slice_lines(s, 3, 4), "d");
+        assert_eq!(slice_lines(s, 0, 10), s);
+        assert_eq!(slice_lines(s, 4, 5), "");
+        assert_eq!(slice_lines(s, 2, 2), "");
+        assert_eq!(slice_lines(s, 2, 1), "");
+        assert_eq!(slice_lines("a\n", 1, 2), "");
+    }
+
+    #[test]
+    fn test_slice_columns() {
+        let s = "a\nb\r\n\u{345}ab\u{898}\rd";
+        assert_eq!(slice_columns(s, 0, 0, 5), "a");
+        assert_eq!(slice_columns(s, 1, 0, 5), "b");
+        assert_eq!(slice_columns(s, 2, 1, 3), "ab");
+        assert_eq!(slice_columns(s, 2, 3, 4), "\u{898}");
+        assert_eq!(slice_columns(s, 3, 0, 1), "d");
+        assert_eq!(slice_columns(s, 4, 0, 1), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_same_as_free_functions() {
+        for s in STRINGS.iter() {
+            let index = LineIndex::new(s);
+            for i in 0..s.len() + 2 {
+                for j in 0..s.len() + 2 {
+                    assert_eq!(index.slice_lines(i, j), slice_lines(s, i, j));
+                    assert_eq!(index.slice_columns(i, 0, j), slice_columns(s, i, 0, j));
+                }
+            }
+
+            let one = LineIndex::with_base(s, Base::One);
+            for i in 0..s.len() + 2 {
+                assert_eq!(one.slice_lines(i + 1, i + 2), slice_lines(s, i, i + 1));
+                assert_eq!(one.line(i + 1), index.line(i));
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_round_trip() {
+        for s in STRINGS.iter() {
+            for &base in [Base::Zero, Base::One].iter() {
+                let index = LineIndex::with_base(s, base);
+                for offset in 0..=crate::len(s) {
+                    let (line, column) = index.position(offset).unwrap();
+                    assert_eq!(index.offset(line, column), Some(offset));
+                }
+                assert_eq!(index.position(crate::len(s) + 1), None);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_line_index() {
+        let index = LineIndex::new("a\nb\r\n\u{345}ab\u{898}\rd");
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line(0), Some("a"));
+        assert_eq!(index.line(1), Some("b"));
+        assert_eq!(index.line(2), Some("\u{345}ab\u{898}"));
+        assert_eq!(index.line(3), Some("d"));
+        assert_eq!(index.line(4), None);
+
+        assert_eq!(index.position(0), Some((0, 0)));
+        assert_eq!(index.position(1), Some((0, 1)));
+        assert_eq!(index.position(2), Some((1, 0)));
+        assert_eq!(index.position(4), Some((1, 2)));
+        assert_eq!(index.position(8), Some((2, 3)));
+        assert_eq!(index.position(10), Some((3, 0)));
+        assert_eq!(index.position(11), Some((3, 1)));
+        assert_eq!(index.position(12), None);
+
+        assert_eq!(index.offset(0, 2), None);
+        assert_eq!(index.offset(1, 2), Some(4));
+        assert_eq!(index.offset(1, 3), None);
+        assert_eq!(index.offset(3, 1), Some(11));
+        assert_eq!(index.offset(3, 2), None);
+        assert_eq!(index.offset(4, 0), None);
+
+        let index = LineIndex::with_base("ab\ncd", Base::One);
+        assert_eq!(index.position(0), Some((1, 1)));
+        assert_eq!(index.position(4), Some((2, 2)));
+        assert_eq!(index.offset(2, 2), Some(4));
+        assert_eq!(index.offset(0, 1), None);
+        assert_eq!(index.offset(1, 0), None);
+        assert_eq!(index.slice_columns(2, 1, 2), "c");
+        assert_eq!(index.slice_columns(2, 0, 2), "");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_empty() {
+        let index = LineIndex::new("");
+        assert_eq!(index.line_count(), 1);
+        assert_eq!(index.line(0), Some(""));
+        assert_eq!(index.position(0), Some((0, 0)));
+        assert_eq!(index.offset(0, 0), Some(0));
+    }
+}
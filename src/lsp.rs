@@ -0,0 +1,372 @@
+//! Conversions between Language Server Protocol positions and byte offsets.
+//!
+//! A [`Position`] in the LSP is a zero-based line together with a zero-based
+//! `character` offset into that line. Since version 3.17 the client and server
+//! negotiate in which unit `character` is counted, see [`PositionEncoding`].
+//! Lines are separated in the same way as in the [`lines`](crate::lines)
+//! module.
+//!
+//! This module is only available with the `alloc` feature enabled.
+
+use core::convert::TryFrom;
+use core::ops;
+
+use crate::count;
+use crate::lines::LineIndex;
+use crate::utf16::{self, Miss};
+
+/// The unit in which the `character` of a [`Position`] is counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PositionEncoding {
+    /// Bytes of the UTF-8 encoding
+    Utf8,
+    /// Code units of the UTF-16 encoding, which is the default of the LSP
+    #[default]
+    Utf16,
+    /// Unicode scalar values, the same as the utf8/unicode characters of the
+    /// top-level functions of this crate
+    Utf32,
+}
+
+impl PositionEncoding {
+    /// Returns the name of the encoding as used in the `positionEncoding`
+    /// capability
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utf8_slice::lsp::PositionEncoding;
+    ///
+    /// assert_eq!(PositionEncoding::Utf16.as_str(), "utf-16");
+    /// ```
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PositionEncoding::Utf8 => "utf-8",
+            PositionEncoding::Utf16 => "utf-16",
+            PositionEncoding::Utf32 => "utf-32",
+        }
+    }
+
+    /// Fetches the length of a string in this encoding
+    fn len(self, s: &str) -> usize {
+        match self {
+            PositionEncoding::Utf8 => s.len(),
+            PositionEncoding::Utf16 => utf16::len(s),
+            PositionEncoding::Utf32 => crate::len(s),
+        }
+    }
+
+    /// Converts an index counted in this encoding into a byte position,
+    /// clamping it to the length of the string
+    fn byte_pos(self, s: &str, idx: usize) -> Option<usize> {
+        match self {
+            PositionEncoding::Utf8 => Some(idx.min(s.len())).filter(|&pos| s.is_char_boundary(pos)),
+            PositionEncoding::Utf16 => match utf16::unit_pos(s, idx) {
+                Ok(pos) => Some(pos),
+                Err(Miss::OutOfBounds) => Some(s.len()),
+                Err(Miss::InsidePair) => None,
+            },
+            PositionEncoding::Utf32 => Some(count::char_pos(s, idx).unwrap_or(s.len())),
+        }
+    }
+}
+
+/// A position in a text document, as defined by the LSP
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Position {
+    /// The line, counted from 0
+    pub line: u32,
+    /// The offset into the line, counted from 0 in the negotiated
+    /// [`PositionEncoding`]
+    pub character: u32,
+}
+
+impl Position {
+    /// Creates a position from a line and a character offset
+    pub fn new(line: u32, character: u32) -> Self {
+        Position { line, character }
+    }
+}
+
+/// A range in a text document, as defined by the LSP
+///
+/// The `end` position is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Range {
+    /// Where the range starts
+    pub start: Position,
+    /// Where the range ends
+    pub end: Position,
+}
+
+impl Range {
+    /// Creates a range from a start and an end position
+    pub fn new(start: Position, end: Position) -> Self {
+        Range { start, end }
+    }
+}
+
+/// A text document which converts between LSP positions and byte offsets
+///
+/// The document stores where every line starts, so a conversion only needs
+/// to look at the line of the position.
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::lsp::{Document, Position, PositionEncoding, Range};
+///
+/// let text = "The 🚀\ngoes to the 🌑!";
+/// let doc = Document::new(text, PositionEncoding::Utf16);
+///
+/// assert_eq!(doc.offset(Position::new(0, 4)), Some(4));
+/// assert_eq!(doc.offset(Position::new(0, 5)), None);
+/// assert_eq!(doc.position(25), Some(Position::new(1, 14)));
+///
+/// let moon = Range::new(Position::new(1, 12), Position::new(1, 14));
+/// assert_eq!(doc.slice(moon), Some("🌑"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Document<'a> {
+    lines: LineIndex<'a>,
+    encoding: PositionEncoding,
+}
+
+impl<'a> Document<'a> {
+    /// Builds a document over `text` which counts characters in `encoding`
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The content of the document
+    /// * `encoding` - The negotiated position encoding
+    pub fn new(text: &'a str, encoding: PositionEncoding) -> Self {
+        Document {
+            lines: LineIndex::new(text),
+            encoding,
+        }
+    }
+
+    /// Returns the content of the document
+    pub fn as_str(&self) -> &'a str {
+        self.lines.as_str()
+    }
+
+    /// Returns the position encoding of the document
+    pub fn encoding(&self) -> PositionEncoding {
+        self.encoding
+    }
+
+    /// Converts a position into a byte offset
+    ///
+    /// A `character` past the end of the line is clamped to the end of the
+    /// line, as required by the LSP.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to convert
+    ///
+    /// # Note
+    /// * Will return `None` for lines past the end of the document and for
+    ///   positions which lie within a character *
+    pub fn offset(&self, position: Position) -> Option<usize> {
+        let line = usize::try_from(position.line).ok()?;
+        let start = self.lines.line_start(line)?;
+        let text = self.lines.line(line)?;
+        let character = usize::try_from(position.character).ok()?;
+
+        Some(start + self.encoding.byte_pos(text, character)?)
+    }
+
+    /// Converts a byte offset into a position
+    ///
+    /// The length of the document is a valid offset and maps to the end of
+    /// the last line.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset to convert
+    ///
+    /// # Note
+    /// * Will return `None` for offsets which are not on a character
+    ///   boundary or lie between the `\r` and `\n` of a line ending *
+    pub fn position(&self, offset: usize) -> Option<Position> {
+        let text = self.as_str();
+        if !text.is_char_boundary(offset) {
+            return None;
+        }
+
+        let line = self.lines.line_at(offset);
+        let start = self.lines.line_start(line)?;
+        let line_text = self.lines.line(line)?;
+        if offset > start + line_text.len() {
+            // No position maps to the middle of a `\r\n`
+            return None;
+        }
+
+        let character = self.encoding.len(&text[start..offset]);
+
+        Some(Position {
+            line: u32::try_from(line).ok()?,
+            character: u32::try_from(character).ok()?,
+        })
+    }
+
+    /// Converts a range into a range of byte offsets
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range to convert
+    ///
+    /// # Note
+    /// * Will return `None` if either position is invalid or the range ends
+    ///   before it starts *
+    pub fn byte_range(&self, range: Range) -> Option<ops::Range<usize>> {
+        let start = self.offset(range.start)?;
+        let end = self.offset(range.end)?;
+
+        if end < start {
+            return None;
+        }
+
+        Some(start..end)
+    }
+
+    /// Converts a range of byte offsets into a range
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The range of byte offsets to convert
+    ///
+    /// # Note
+    /// * Will return `None` if either offset is invalid or the range ends
+    ///   before it starts *
+    pub fn range(&self, bytes: ops::Range<usize>) -> Option<Range> {
+        if bytes.end < bytes.start {
+            return None;
+        }
+
+        Some(Range {
+            start: self.position(bytes.start)?,
+            end: self.position(bytes.end)?,
+        })
+    }
+
+    /// Fetches the text within a range
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of the text
+    ///
+    /// # Note
+    /// * Will return `None` for invalid ranges *
+    pub fn slice(&self, range: Range) -> Option<&'a str> {
+        self.byte_range(range).map(|bytes| &self.as_str()[bytes])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENCODINGS: [PositionEncoding; 3] = [
+        PositionEncoding::Utf8,
+        PositionEncoding::Utf16,
+        PositionEncoding::Utf32,
+    ];
+
+    const STRINGS: [&str; 5] = [
+        "",
+        "a\nb\r\nc\rd\n",
+        "\u{345}ab\u{898}\r\n\r\nxyz",
+        "The 🚀\ngoes to the 🌑!",
+        "🚀🚀\n👨‍🚀",
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for s in STRINGS.iter() {
+            for &encoding in ENCODINGS.iter() {
+                let doc = Document::new(s, encoding);
+                for offset in 0..s.len() + 2 {
+                    let within_crlf = s.get(..offset).is_some_and(|before| {
+                        before.ends_with('\r') && s[offset..].starts_with('\n')
+                    });
+                    match doc.position(offset) {
+                        Some(position) => {
+                            assert_eq!(doc.offset(position), Some(offset));
+                        }
+                        None => assert!(!s.is_char_boundary(offset) || within_crlf),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_encodings() {
+        let s = "a🚀b\nö";
+        let positions = [
+            (PositionEncoding::Utf8, [1, 5, 6], 2),
+            (PositionEncoding::Utf16, [1, 3, 4], 1),
+            (PositionEncoding::Utf32, [1, 2, 3], 1),
+        ];
+
+        for &(encoding, characters, end) in positions.iter() {
+            let doc = Document::new(s, encoding);
+            for (&character, &offset) in characters.iter().zip([1, 5, 6].iter()) {
+                assert_eq!(doc.offset(Position::new(0, character)), Some(offset));
+                assert_eq!(doc.position(offset), Some(Position::new(0, character)));
+            }
+            assert_eq!(doc.position(s.len()), Some(Position::new(1, end)));
+        }
+    }
+
+    #[test]
+    fn test_within_character() {
+        let doc = Document::new("a🚀b", PositionEncoding::Utf16);
+        assert_eq!(doc.offset(Position::new(0, 2)), None);
+
+        let doc = Document::new("a🚀b", PositionEncoding::Utf8);
+        for character in 2..5 {
+            assert_eq!(doc.offset(Position::new(0, character)), None);
+        }
+        assert_eq!(doc.position(2), None);
+
+        let doc = Document::new("ab\r\ncd\ref", PositionEncoding::Utf16);
+        assert_eq!(doc.position(3), None);
+        assert_eq!(doc.position(2), Some(Position::new(0, 2)));
+        assert_eq!(doc.position(4), Some(Position::new(1, 0)));
+        assert_eq!(doc.position(7), Some(Position::new(2, 0)));
+    }
+
+    #[test]
+    fn test_clamps_character() {
+        for &encoding in ENCODINGS.iter() {
+            let doc = Document::new("ab\r\ncd", encoding);
+            assert_eq!(doc.offset(Position::new(0, 2)), Some(2));
+            assert_eq!(doc.offset(Position::new(0, 3)), Some(2));
+            assert_eq!(doc.offset(Position::new(0, u32::MAX)), Some(2));
+            assert_eq!(doc.offset(Position::new(1, 9)), Some(6));
+            assert_eq!(doc.offset(Position::new(2, 0)), None);
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let doc = Document::new("The 🚀\ngoes to the 🌑!", PositionEncoding::Utf32);
+        let rocket = Range::new(Position::new(0, 4), Position::new(0, 5));
+        assert_eq!(doc.byte_range(rocket), Some(4..8));
+        assert_eq!(doc.range(4..8), Some(rocket));
+        assert_eq!(doc.slice(rocket), Some("🚀"));
+
+        let reversed = Range::new(rocket.end, rocket.start);
+        assert_eq!(doc.byte_range(reversed), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let backwards = 8..4;
+        assert_eq!(doc.range(backwards), None);
+        assert_eq!(doc.range(4..6), None);
+
+        let lines = Range::new(Position::new(0, 4), Position::new(1, 4));
+        assert_eq!(doc.slice(lines), Some("🚀\ngoes"));
+    }
+}
@@ -0,0 +1,376 @@
+use std::io::{self, BufRead, Write};
+
+/// Tracks the character position in a stream of UTF-8 text, which allows for
+/// extracting text between character offsets without loading all of it into
+/// memory.
+///
+/// The reader only moves forward. UTF-8 sequences may be split across the
+/// buffers of the underlying reader, the character position always accounts
+/// for whole characters.
+///
+/// This type is only available with the `std` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::BufReader;
+/// use utf8_slice::CharReader;
+///
+/// let text = "The 🚀 goes to the 🌑!";
+/// let mut reader = CharReader::new(BufReader::with_capacity(3, text.as_bytes()));
+///
+/// let mut rocket = Vec::new();
+/// reader.extract(4, 5, &mut rocket)?;
+/// assert_eq!(rocket, "🚀".as_bytes());
+///
+/// let mut moon = Vec::new();
+/// reader.extract(18, 19, &mut moon)?;
+/// assert_eq!(moon, "🌑".as_bytes());
+/// assert_eq!(reader.position(), 19);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct CharReader<R> {
+    inner: R,
+    position: usize,
+}
+
+impl<R: BufRead> CharReader<R> {
+    /// Creates a reader which starts counting characters at 0
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The reader to read UTF-8 text from
+    pub fn new(inner: R) -> Self {
+        CharReader { inner, position: 0 }
+    }
+
+    /// Returns the amount of characters which have been read so far
+    ///
+    /// The position also stays in sync with the underlying reader when an
+    /// operation fails. Invalid UTF-8 is consumed up to the first invalid
+    /// byte, and the position then counts every character of which the first
+    /// byte was consumed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Skips over the next `n` characters
+    ///
+    /// Returns the amount of characters which were skipped, which is less than
+    /// `n` if the stream ended first.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The amount of characters to skip
+    pub fn skip(&mut self, n: usize) -> io::Result<usize> {
+        self.advance(n, &mut io::sink())
+    }
+
+    /// Copies the next `n` characters into a writer
+    ///
+    /// Returns the amount of characters which were copied, which is less than
+    /// `n` if the stream ended first.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The amount of characters to copy
+    /// * `writer` - Where to write the characters to
+    pub fn copy_chars<W: Write>(&mut self, n: usize, writer: &mut W) -> io::Result<usize> {
+        self.advance(n, writer)
+    }
+
+    /// Copies the characters from index `begin` until index `end` into a
+    /// writer, skipping everything before `begin`.
+    ///
+    /// This behaves like [`slice`](fn@crate::slice) does on the whole text,
+    /// so it copies nothing if `end` lies before `begin` and stops early if
+    /// the stream ends before `end`. Returns the amount of characters which
+    /// were copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `begin` - The character index where the copied text begins
+    /// * `end` - The character index where the copied text ends
+    /// * `writer` - Where to write the characters to
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if `begin` lies before the
+    /// current position, since the reader cannot go back.
+    pub fn extract<W: Write>(
+        &mut self,
+        begin: usize,
+        end: usize,
+        writer: &mut W,
+    ) -> io::Result<usize> {
+        if begin < self.position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot extract text before the current position",
+            ));
+        }
+
+        if end <= begin {
+            return Ok(0);
+        }
+
+        let skip = begin - self.position;
+        if self.skip(skip)? < skip {
+            return Ok(0);
+        }
+
+        self.copy_chars(end - begin, writer)
+    }
+
+    /// Consumes the next `n` characters, passing all of their bytes to
+    /// `writer`
+    fn advance<W: Write>(&mut self, n: usize, writer: &mut W) -> io::Result<usize> {
+        let mut done = 0;
+        // The bytes of a character which is split across two buffers
+        let mut partial = [0; 4];
+        let mut partial_len = 0;
+        let mut width = 0;
+
+        // Stop as soon as the last character is complete, reading any further
+        // could block on input which was never asked for
+        while done < n || partial_len != 0 {
+            let buf = match self.inner.fill_buf() {
+                Ok(buf) => buf,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+
+            if buf.is_empty() {
+                if partial_len != 0 {
+                    return Err(invalid_utf8());
+                }
+                break;
+            }
+
+            let started = done;
+            let mut consumed = 0;
+            let mut valid = true;
+            for &byte in buf {
+                if partial_len != 0 {
+                    if byte & 0b1100_0000 != 0b1000_0000 {
+                        valid = false;
+                        break;
+                    }
+
+                    partial[partial_len] = byte;
+                    partial_len += 1;
+                    if partial_len == width {
+                        if core::str::from_utf8(&partial[..width]).is_err() {
+                            valid = false;
+                            break;
+                        }
+                        partial_len = 0;
+                    }
+                } else {
+                    if done == n {
+                        break;
+                    }
+
+                    match utf8_width(byte) {
+                        Some(byte_width) => width = byte_width,
+                        None => {
+                            valid = false;
+                            break;
+                        }
+                    }
+                    if width > 1 {
+                        partial[0] = byte;
+                        partial_len = 1;
+                    }
+                    done += 1;
+                }
+
+                consumed += 1;
+            }
+
+            // Pass on everything before an invalid byte, so the position
+            // does not depend on how the input is split into buffers
+            writer.write_all(&buf[..consumed])?;
+            self.inner.consume(consumed);
+            self.position += done - started;
+
+            if !valid {
+                return Err(invalid_utf8());
+            }
+        }
+
+        Ok(done)
+    }
+}
+
+/// Fetches the length of a UTF-8 sequence from its first byte
+fn utf8_width(byte: u8) -> Option<usize> {
+    match byte {
+        0x00..=0x7F => Some(1),
+        0xC2..=0xDF => Some(2),
+        0xE0..=0xEF => Some(3),
+        0xF0..=0xF4 => Some(4),
+        _ => None,
+    }
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "stream did not contain valid UTF-8",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+    use std::vec::Vec;
+
+    const TEXT: &str = "The 🚀 goes to the 🌑! \u{345}ab\u{898}xyz";
+
+    fn reader(text: &[u8], capacity: usize) -> CharReader<BufReader<&[u8]>> {
+        CharReader::new(BufReader::with_capacity(capacity, text))
+    }
+
+    #[test]
+    fn test_same_as_slice() {
+        let len = crate::len(TEXT);
+        for capacity in 1..6 {
+            for i in 0..len + 2 {
+                for j in 0..len + 2 {
+                    let mut out = Vec::new();
+                    let copied = reader(TEXT.as_bytes(), capacity)
+                        .extract(i, j, &mut out)
+                        .unwrap();
+                    assert_eq!(out, crate::slice(TEXT, i, j).as_bytes());
+                    assert_eq!(copied, crate::len(crate::slice(TEXT, i, j)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_consecutive_extracts() {
+        let mut reader = reader(TEXT.as_bytes(), 2);
+        let mut out = Vec::new();
+        assert_eq!(reader.extract(4, 5, &mut out).unwrap(), 1);
+        assert_eq!(reader.extract(5, 10, &mut out).unwrap(), 5);
+        assert_eq!(out, crate::slice(TEXT, 4, 10).as_bytes());
+        assert_eq!(reader.position(), 10);
+
+        assert_eq!(
+            reader.extract(4, 6, &mut out).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_skip_and_copy() {
+        let mut reader = reader(TEXT.as_bytes(), 3);
+        assert_eq!(reader.skip(18).unwrap(), 18);
+
+        let mut out = Vec::new();
+        assert_eq!(reader.copy_chars(1, &mut out).unwrap(), 1);
+        assert_eq!(out, "🌑".as_bytes());
+
+        assert_eq!(reader.skip(100).unwrap(), crate::len(TEXT) - 19);
+        assert_eq!(reader.position(), crate::len(TEXT));
+        assert_eq!(reader.skip(1).unwrap(), 0);
+    }
+
+    /// Hands out the given chunks and panics when read past them
+    struct Chunked<'a> {
+        chunks: &'a [&'a [u8]],
+        offset: usize,
+    }
+
+    impl io::Read for Chunked<'_> {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            unreachable!("CharReader only reads through BufRead")
+        }
+    }
+
+    impl BufRead for Chunked<'_> {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            match self.chunks.first() {
+                Some(chunk) => Ok(&chunk[self.offset..]),
+                None => panic!("read past the requested characters"),
+            }
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.offset += amt;
+            if self.offset == self.chunks[0].len() {
+                self.chunks = &self.chunks[1..];
+                self.offset = 0;
+            }
+        }
+    }
+
+    #[test]
+    fn test_stops_after_last_char() {
+        let chunks: [&[u8]; 3] = [b"a\xF0\x9F", b"\x9A\x80b", "🌑".as_bytes()];
+        let mut reader = CharReader::new(Chunked {
+            chunks: &chunks,
+            offset: 0,
+        });
+
+        let mut out = Vec::new();
+        assert_eq!(reader.copy_chars(2, &mut out).unwrap(), 2);
+        assert_eq!(out, "a🚀".as_bytes());
+        assert_eq!(reader.extract(2, 4, &mut out).unwrap(), 2);
+        assert_eq!(out, "a🚀b🌑".as_bytes());
+        assert_eq!(reader.skip(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_position_after_error() {
+        for capacity in 1..9 {
+            let mut reader = reader(b"abcd\xFFef", capacity);
+            assert_eq!(
+                reader.skip(10).unwrap_err().kind(),
+                io::ErrorKind::InvalidData
+            );
+            assert_eq!(reader.position(), 4);
+        }
+
+        let mut reader = reader("a🚀b".as_bytes(), 2);
+        let mut out = [0; 2];
+        let err = reader.copy_chars(3, &mut &mut out[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(reader.position(), 2);
+        assert_eq!(&out, b"a\xF0");
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let inputs: [&[u8]; 4] = [
+            b"ab\xFFcd",
+            b"ab\xF0\x9Fcd",
+            b"ab\xF0\x9F",
+            b"ab\xED\xA0\x80",
+        ];
+        for input in inputs.iter() {
+            for capacity in 1..4 {
+                let mut reader = reader(input, capacity);
+                assert_eq!(reader.skip(2).unwrap(), 2);
+                assert_eq!(
+                    reader.skip(3).unwrap_err().kind(),
+                    io::ErrorKind::InvalidData
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Sentence aware versions of the slice utilities.
+//!
+//! Sentences are found with the sentence boundaries defined by
+//! [UAX #29](https://www.unicode.org/reports/tr29/). A sentence includes its
+//! closing punctuation and quotes and the whitespace after it, so the
+//! sentences of a string always add up to the whole string.
+//!
+//! The boundaries are found without a dictionary, so an abbreviation followed
+//! by an uppercase word, as in `"Mr. Smith"`, still ends a sentence.
+//!
+//! This module is only available with the `sentences` feature enabled.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Fetches a slice of a string from a begin to an end index
+/// taking into account sentence indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑. Will it land? Nobody knows.";
+///
+/// let question = utf8_slice::sentences::slice(s, 1, 2);
+/// # assert_eq!(utf8_slice::sentences::slice(s, 1, 2), "Will it land? ");
+/// // Will equal "Will it land? "
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn slice(s: &str, begin: usize, end: usize) -> &str {
+    if end <= begin {
+        return "";
+    }
+
+    let mut boundaries = s
+        .split_sentence_bound_indices()
+        .map(|(pos, _)| pos)
+        .chain(Some(s.len()));
+
+    boundaries
+        .nth(begin)
+        .filter(|&start_pos| start_pos < s.len())
+        .map(|start_pos| {
+            let end_pos = boundaries.nth(end - begin - 1).unwrap_or(s.len());
+            &s[start_pos..end_pos]
+        })
+        .unwrap_or("")
+}
+
+/// Fetches a slice of a string from a starting index
+/// taking into account sentence indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑. Will it land? Nobody knows.";
+///
+/// let rest = utf8_slice::sentences::from(s, 1);
+/// # assert_eq!(utf8_slice::sentences::from(s, 1), "Will it land? Nobody knows.");
+/// // Will equal "Will it land? Nobody knows."
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn from(s: &str, begin: usize) -> &str {
+    s.split_sentence_bound_indices()
+        .nth(begin)
+        .map(|(start_pos, _)| &s[start_pos..])
+        .unwrap_or("")
+}
+
+/// Fetches a slice of a string until an ending index
+/// taking into account sentence indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑. Will it land? Nobody knows.";
+///
+/// let abstract_ = utf8_slice::sentences::till(s, 2);
+/// # assert_eq!(utf8_slice::sentences::till(s, 2), "The 🚀 goes to the 🌑. Will it land? ");
+/// // Will equal "The 🚀 goes to the 🌑. Will it land? "
+/// ```
+pub fn till(s: &str, end: usize) -> &str {
+    s.split_sentence_bound_indices()
+        .nth(end)
+        .map(|(end_pos, _)| &s[..end_pos])
+        .unwrap_or(s)
+}
+
+/// Fetches the length in sentences of an utf8/unicode string
+///
+/// # Arguments
+///
+/// * `s` - The string of which to fetch the length
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(utf8_slice::sentences::len("Hi there. How are you?"), 2);
+/// assert_eq!(utf8_slice::sentences::len(""), 0);
+/// ```
+pub fn len(s: &str) -> usize {
+    s.split_sentence_bounds().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice() {
+        let s = "One.  Two?\nThree";
+        assert_eq!(slice(s, 0, 1), "One.  ");
+        assert_eq!(slice(s, 1, 2), "Two?\n");
+        assert_eq!(slice(s, 1, 10), "Two?\nThree");
+        assert_eq!(slice(s, 0, 3), s);
+        assert_eq!(slice(s, 3, 4), "");
+        assert_eq!(slice(s, 2, 2), "");
+        assert_eq!(slice(s, 2, 1), "");
+    }
+
+    #[test]
+    fn test_abbreviations() {
+        let s = "Mr. Smith went home. He slept.";
+        assert_eq!(len(s), 3);
+        assert_eq!(slice(s, 0, 1), "Mr. ");
+        assert_eq!(slice(s, 1, 2), "Smith went home. ");
+
+        let s = "It costs 3.50 e.g. the cheap one. Fine.";
+        assert_eq!(len(s), 2);
+        assert_eq!(till(s, 1), "It costs 3.50 e.g. the cheap one. ");
+    }
+
+    #[test]
+    fn test_cjk() {
+        let s = "你好。我很好。谢谢！";
+        assert_eq!(len(s), 3);
+        assert_eq!(slice(s, 1, 2), "我很好。");
+        assert_eq!(from(s, 2), "谢谢！");
+    }
+
+    #[test]
+    fn test_quotes() {
+        let s = "\"Stop!\" she said. \"Why?\" He asked.";
+        assert_eq!(len(s), 4);
+        assert_eq!(slice(s, 0, 1), "\"Stop!\" ");
+        assert_eq!(slice(s, 2, 3), "\"Why?\" ");
+    }
+
+    #[test]
+    fn test_from_till() {
+        let s = "A b. C d! E f?";
+        for i in 0..5 {
+            assert_eq!(till(s, i).len() + from(s, i).len(), s.len());
+        }
+        assert_eq!(till(s, 0), "");
+        assert_eq!(till(s, 10), s);
+        assert_eq!(from(s, 0), s);
+        assert_eq!(from(s, 3), "");
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(len(""), 0);
+        assert_eq!(len("no full stop"), 1);
+        assert_eq!(len("One. Two. Three."), 3);
+    }
+}
@@ -0,0 +1,368 @@
+//! Slice utilities which accept negative indices.
+//!
+//! Like in Python, a negative index counts characters from the end of the
+//! string, so `-1` refers to the last character. Indices which fall outside of
+//! the string are clamped to its start or end instead of resulting in an
+//! error. Negative indices are resolved by walking the string backwards, so
+//! taking a slice from the tail of a long string does not scan all of it.
+
+use core::str::Chars;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Fetches a slice of a string from a begin to an end index
+/// taking into account utf8/unicode character indices.
+///
+/// This behaves the same as `s[begin:end]` in Python.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins, negative values count from the end
+/// * `end` - Where the slice ends, negative values count from the end
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let moon = utf8_slice::signed::slice(s, -2, -1);
+/// # assert_eq!(utf8_slice::signed::slice(s, -2, -1), "🌑");
+/// // Will equal "🌑"
+/// ```
+///
+/// # Note
+/// * Will return an empty string if `begin` lies at or after `end` *
+pub fn slice(s: &str, begin: isize, end: isize) -> &str {
+    let start_pos = byte_pos(s, begin);
+    let end_pos = byte_pos(s, end);
+
+    if start_pos < end_pos {
+        &s[start_pos..end_pos]
+    } else {
+        ""
+    }
+}
+
+/// Fetches a slice of a string from a starting index
+/// taking into account utf8/unicode character indices.
+///
+/// This behaves the same as `s[begin:]` in Python.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins, negative values count from the end
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let the_moon = utf8_slice::signed::from(s, -6);
+/// # assert_eq!(utf8_slice::signed::from(s, -6), "the 🌑!");
+/// // Will equal "the 🌑!"
+/// ```
+pub fn from(s: &str, begin: isize) -> &str {
+    &s[byte_pos(s, begin)..]
+}
+
+/// Fetches a slice of a string until an ending index
+/// taking into account utf8/unicode character indices.
+///
+/// This behaves the same as `s[:end]` in Python.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end` - Where the slice ends, negative values count from the end
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let without_exclamation = utf8_slice::signed::till(s, -1);
+/// # assert_eq!(utf8_slice::signed::till(s, -1), "The 🚀 goes to the 🌑");
+/// // Will equal "The 🚀 goes to the 🌑"
+/// ```
+pub fn till(s: &str, end: isize) -> &str {
+    &s[..byte_pos(s, end)]
+}
+
+/// Fetches every `step`-th character of a string between a begin and an end
+/// index taking into account utf8/unicode character indices.
+///
+/// This behaves the same as `s[begin:end:step]` in Python. With a positive
+/// `step` the characters of [`slice(s, begin, end)`](fn@slice) are yielded front
+/// to back. With a negative `step` the characters are yielded back to front,
+/// starting at `begin` and stopping before reaching `end`.
+///
+/// The characters are yielded lazily, use `slice_step_string` to collect
+/// them into a `String`.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the characters from
+/// * `begin` - Where the selection begins, negative values count from the end
+/// * `end` - Where the selection ends, negative values count from the end
+/// * `step` - The distance between two selected characters
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::signed::slice_step;
+///
+/// let s = "🚀a🌑b🚀c";
+///
+/// assert!(slice_step(s, 0, 6, 2).eq("🚀🌑🚀".chars()));
+/// assert!(slice_step(s, -1, -7, -2).eq("cba".chars()));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `step` is 0.
+pub fn slice_step(s: &str, begin: isize, end: isize, step: isize) -> StepChars<'_> {
+    assert!(step != 0, "slice step cannot be zero");
+
+    let selection = if step > 0 {
+        slice(s, begin, end)
+    } else {
+        let start_pos = byte_pos_after(s, end);
+        let end_pos = byte_pos_after(s, begin);
+
+        if start_pos < end_pos {
+            &s[start_pos..end_pos]
+        } else {
+            ""
+        }
+    };
+
+    StepChars {
+        chars: selection.chars(),
+        step: step.unsigned_abs(),
+        reverse: step < 0,
+        started: false,
+    }
+}
+
+/// Collects every `step`-th character of a string between a begin and an
+/// end index into a [`String`].
+///
+/// This is a convenience wrapper around [`slice_step`], which is only
+/// available with the `alloc` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// let reversed = utf8_slice::signed::slice_step_string(s, -1, isize::MIN, -1);
+/// # assert_eq!(utf8_slice::signed::slice_step_string(s, -1, isize::MIN, -1), "!🌑 eht ot seog 🚀 ehT");
+/// // Will equal "!🌑 eht ot seog 🚀 ehT"
+/// ```
+///
+/// # Panics
+///
+/// Panics if `step` is 0.
+#[cfg(feature = "alloc")]
+pub fn slice_step_string(s: &str, begin: isize, end: isize, step: isize) -> String {
+    slice_step(s, begin, end, step).collect()
+}
+
+/// An iterator over every `step`-th character of a string.
+///
+/// This struct is created by [`slice_step`].
+#[derive(Debug, Clone)]
+pub struct StepChars<'a> {
+    chars: Chars<'a>,
+    step: usize,
+    reverse: bool,
+    started: bool,
+}
+
+impl<'a> StepChars<'a> {
+    fn next_char(&mut self) -> Option<char> {
+        if self.reverse {
+            self.chars.next_back()
+        } else {
+            self.chars.next()
+        }
+    }
+}
+
+impl<'a> Iterator for StepChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.started {
+            for _ in 1..self.step {
+                self.next_char()?;
+            }
+        }
+
+        self.started = true;
+        self.next_char()
+    }
+}
+
+/// Resolves a possibly negative character index to a byte position,
+/// clamping it to the bounds of the string.
+fn byte_pos(s: &str, idx: isize) -> usize {
+    if idx >= 0 {
+        s.char_indices()
+            .nth(idx as usize)
+            .map(|(pos, _)| pos)
+            .unwrap_or_else(|| s.len())
+    } else {
+        s.char_indices()
+            .rev()
+            .nth(idx.unsigned_abs() - 1)
+            .map(|(pos, _)| pos)
+            .unwrap_or(0)
+    }
+}
+
+/// Resolves a possibly negative character index to the byte position right
+/// after that character, clamping it to the bounds of the string.
+///
+/// This is used for negative steps, where Python clamps out of bounds indices
+/// to the last character and to right before the first character.
+fn byte_pos_after(s: &str, idx: isize) -> usize {
+    match idx {
+        -1 => s.len(),
+        idx if idx < 0 => byte_pos(s, idx + 1),
+        idx => byte_pos(s, idx.saturating_add(1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::String;
+    use std::vec::Vec;
+
+    /// Python's slicing behaviour on a list of characters
+    fn python_slice(s: &str, begin: isize, end: isize) -> String {
+        let chars: Vec<char> = s.chars().collect();
+        let len = chars.len() as isize;
+        let clamp = |idx: isize| {
+            if idx < 0 {
+                (idx + len).max(0)
+            } else {
+                idx.min(len)
+            }
+        };
+
+        let (begin, end) = (clamp(begin), clamp(end));
+        if begin < end {
+            chars[begin as usize..end as usize].iter().collect()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Python's extended slicing behaviour on a list of characters
+    #[cfg(feature = "alloc")]
+    fn python_slice_step(s: &str, begin: isize, end: isize, step: isize) -> String {
+        let chars: Vec<char> = s.chars().collect();
+        let len = chars.len() as isize;
+        let (lower, upper) = if step < 0 { (-1, len - 1) } else { (0, len) };
+        let clamp = |idx: isize| {
+            if idx < 0 {
+                (idx + len).max(lower)
+            } else {
+                idx.min(upper)
+            }
+        };
+
+        let (mut idx, end) = (clamp(begin), clamp(end));
+        let mut result = String::new();
+        while (step > 0 && idx < end) || (step < 0 && idx > end) {
+            result.push(chars[idx as usize]);
+            idx += step;
+        }
+        result
+    }
+
+    #[test]
+    fn test_same_as_python() {
+        for s in ["", "abc", "\u{345}ab\u{898}xyz"].iter() {
+            for i in -10..10 {
+                for j in -10..10 {
+                    assert_eq!(slice(s, i, j), python_slice(s, i, j));
+                }
+                assert_eq!(from(s, i), python_slice(s, i, isize::MAX));
+                assert_eq!(till(s, i), python_slice(s, 0, i));
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_slice_step_same_as_python() {
+        for s in ["", "abc", "\u{345}ab\u{898}xyz"].iter() {
+            for i in -10..10 {
+                for j in -10..10 {
+                    for step in [-4, -3, -2, -1, 1, 2, 3, 4].iter() {
+                        assert_eq!(
+                            slice_step_string(s, i, j, *step),
+                            python_slice_step(s, i, j, *step)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_slice_step() {
+        assert_eq!(
+            slice_step_string("\u{345}ab\u{898}xyz", 0, 7, 3),
+            "\u{345}\u{898}z"
+        );
+        assert_eq!(slice_step_string("\u{345}ab\u{898}xyz", 6, 0, -2), "zxb");
+        assert_eq!(
+            slice_step_string("\u{345}ab\u{898}xyz", isize::MAX, isize::MIN, -1),
+            "zyx\u{898}ba\u{345}"
+        );
+        assert_eq!(
+            slice_step_string("\u{345}ab\u{898}xyz", 0, 7, isize::MAX),
+            "\u{345}"
+        );
+        assert_eq!(slice_step("\u{345}ab\u{898}xyz", 1, 4, 1).count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_step_zero() {
+        slice_step("abc", 0, 3, 0);
+    }
+
+    #[test]
+    fn test_slice() {
+        assert_eq!(slice("\u{345}ab\u{898}xyz", 1, -3), "ab\u{898}");
+        assert_eq!(slice("\u{345}ab\u{898}xyz", -3, 7), "xyz");
+        assert_eq!(slice("\u{345}ab\u{898}xyz", -1, -3), "");
+        assert_eq!(
+            slice("\u{345}ab\u{898}xyz", isize::MIN, isize::MAX),
+            "\u{345}ab\u{898}xyz"
+        );
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(from("\u{345}ab\u{898}xyz", -4), "\u{898}xyz");
+        assert_eq!(from("\u{345}ab\u{898}xyz", -10), "\u{345}ab\u{898}xyz");
+        assert_eq!(from("\u{345}ab\u{898}xyz", 10), "");
+    }
+
+    #[test]
+    fn test_till() {
+        assert_eq!(till("\u{345}ab\u{898}xyz", -1), "\u{345}ab\u{898}xy");
+        assert_eq!(till("\u{345}ab\u{898}xyz", -10), "");
+        assert_eq!(till("\u{345}ab\u{898}xyz", 10), "\u{345}ab\u{898}xyz");
+    }
+}
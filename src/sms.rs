@@ -0,0 +1,328 @@
+//! Splitting text into SMS segments.
+//!
+//! An SMS is sent in the GSM 7-bit default alphabet if every character of the
+//! message is part of it, and in UCS-2 otherwise. A single SMS holds 160
+//! septets in GSM-7 or 70 code units in UCS-2. Longer messages are sent as
+//! several concatenated segments, which lose some room to the User Data
+//! Header and hold 153 septets or 67 code units each.
+//!
+//! Characters of the GSM-7 extension table, such as `'€'` or `'{'`, are sent
+//! as an escape followed by the character and take up two septets. Characters
+//! outside of the Basic Multilingual Plane, such as `'🚀'`, take up two code
+//! units in UCS-2, as modern phones decode UCS-2 messages as UTF-16. A
+//! segment never ends between the two halves of either of them.
+
+use core::iter::FusedIterator;
+
+/// The encoding in which an SMS is sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// The GSM 7-bit default alphabet with its extension table
+    Gsm7,
+    /// UCS-2, extended to UTF-16 for characters outside of the Basic
+    /// Multilingual Plane
+    Ucs2,
+}
+
+impl Encoding {
+    /// Fetches the amount of septets or code units a message of a single
+    /// segment can hold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utf8_slice::sms::Encoding;
+    ///
+    /// assert_eq!(Encoding::Gsm7.single_limit(), 160);
+    /// assert_eq!(Encoding::Ucs2.single_limit(), 70);
+    /// ```
+    pub fn single_limit(self) -> usize {
+        match self {
+            Encoding::Gsm7 => 160,
+            Encoding::Ucs2 => 70,
+        }
+    }
+
+    /// Fetches the amount of septets or code units every segment of a message
+    /// of several segments can hold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utf8_slice::sms::Encoding;
+    ///
+    /// assert_eq!(Encoding::Gsm7.multi_limit(), 153);
+    /// assert_eq!(Encoding::Ucs2.multi_limit(), 67);
+    /// ```
+    pub fn multi_limit(self) -> usize {
+        match self {
+            Encoding::Gsm7 => 153,
+            Encoding::Ucs2 => 67,
+        }
+    }
+
+    /// The amount of septets or code units a character takes up
+    fn units(self, c: char) -> usize {
+        match self {
+            Encoding::Gsm7 => septets(c).unwrap_or(0),
+            Encoding::Ucs2 => c.len_utf16(),
+        }
+    }
+}
+
+/// Fetches the amount of septets a character takes up in the GSM 7-bit
+/// default alphabet
+///
+/// # Arguments
+///
+/// * `c` - The character to look up
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::sms;
+///
+/// assert_eq!(sms::septets('a'), Some(1));
+/// assert_eq!(sms::septets('€'), Some(2));
+/// assert_eq!(sms::septets('🚀'), None);
+/// ```
+///
+/// # Note
+/// * Will return `None` for characters which cannot be sent in GSM-7 *
+pub fn septets(c: char) -> Option<usize> {
+    match c {
+        ' '..='?' | 'A'..='Z' | 'a'..='z' | '@' | '_' | '\n' | '\r' => Some(1),
+        '£' | '¥' | 'è' | 'é' | 'ù' | 'ì' | 'ò' | 'Ç' | 'Ø' | 'ø' | 'Å' | 'å' => {
+            Some(1)
+        }
+        'Δ' | 'Φ' | 'Γ' | 'Λ' | 'Ω' | 'Π' | 'Ψ' | 'Σ' | 'Θ' | 'Ξ' => Some(1),
+        'Æ' | 'æ' | 'ß' | 'É' | '¤' | '¡' | 'Ä' | 'Ö' | 'Ñ' | 'Ü' | '§' => Some(1),
+        '¿' | 'ä' | 'ö' | 'ñ' | 'ü' | 'à' => Some(1),
+        '\x0C' | '^' | '{' | '}' | '\\' | '[' | '~' | ']' | '|' | '€' => Some(2),
+        _ => None,
+    }
+}
+
+/// Detects the encoding in which a message has to be sent
+///
+/// # Arguments
+///
+/// * `s` - The message
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::sms::{self, Encoding};
+///
+/// assert_eq!(sms::encoding("Price: 5€ {approx.}"), Encoding::Gsm7);
+/// assert_eq!(sms::encoding("The 🚀 goes to the 🌑!"), Encoding::Ucs2);
+/// ```
+pub fn encoding(s: &str) -> Encoding {
+    if s.chars().all(|c| septets(c).is_some()) {
+        Encoding::Gsm7
+    } else {
+        Encoding::Ucs2
+    }
+}
+
+/// Fetches the length of a message in septets or code units, depending on
+/// the encoding in which it has to be sent
+///
+/// # Arguments
+///
+/// * `s` - The message
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(utf8_slice::sms::len("5€"), 3);
+/// assert_eq!(utf8_slice::sms::len("5🚀"), 3);
+/// ```
+pub fn len(s: &str) -> usize {
+    let encoding = encoding(s);
+    s.chars().map(|c| encoding.units(c)).sum()
+}
+
+/// Fetches the amount of segments in which a message is sent
+///
+/// An empty message has no segments.
+///
+/// # Arguments
+///
+/// * `s` - The message
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::sms;
+///
+/// assert_eq!(sms::segment_count(""), 0);
+/// assert_eq!(sms::segment_count(&"a".repeat(160)), 1);
+/// assert_eq!(sms::segment_count(&"a".repeat(161)), 2);
+/// assert_eq!(sms::segment_count(&"€".repeat(80)), 1);
+/// assert_eq!(sms::segment_count(&"🚀".repeat(35)), 1);
+/// assert_eq!(sms::segment_count(&"🚀".repeat(36)), 2);
+/// ```
+pub fn segment_count(s: &str) -> usize {
+    segments(s).count()
+}
+
+/// Splits a message into the segments in which it is sent
+///
+/// # Arguments
+///
+/// * `s` - The message
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::sms;
+///
+/// let message = "a".repeat(152) + "€ and more";
+/// let segments: Vec<&str> = sms::segments(&message).collect();
+///
+/// // The escape of the '€' does not fit into the first segment
+/// assert_eq!(segments, [&message[..152], "€ and more"]);
+/// ```
+pub fn segments(s: &str) -> Segments<'_> {
+    let encoding = encoding(s);
+    let len: usize = s.chars().map(|c| encoding.units(c)).sum();
+
+    let limit = if len <= encoding.single_limit() {
+        encoding.single_limit()
+    } else {
+        encoding.multi_limit()
+    };
+
+    Segments {
+        rest: s,
+        encoding,
+        limit,
+    }
+}
+
+/// An iterator over the segments of a message
+///
+/// This struct is created by [`segments`].
+#[derive(Debug, Clone)]
+pub struct Segments<'a> {
+    rest: &'a str,
+    encoding: Encoding,
+    limit: usize,
+}
+
+impl Segments<'_> {
+    /// Returns the encoding in which the segments are sent
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let mut units = 0;
+        let mut end_pos = self.rest.len();
+        for (pos, c) in self.rest.char_indices() {
+            units += self.encoding.units(c);
+            if units > self.limit {
+                end_pos = pos;
+                break;
+            }
+        }
+
+        let (segment, rest) = self.rest.split_at(end_pos);
+        self.rest = rest;
+        Some(segment)
+    }
+}
+
+impl FusedIterator for Segments<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_septets() {
+        let basic = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+                     ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+        assert_eq!(basic.chars().count(), 127);
+        assert!(basic.chars().all(|c| septets(c) == Some(1)));
+
+        let extension = "\x0C^{}\\[~]|€";
+        assert!(extension.chars().all(|c| septets(c) == Some(2)));
+
+        for c in ['`', '\t', 'ç', 'á', '中', '🚀'].iter() {
+            assert_eq!(septets(*c), None);
+        }
+    }
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(encoding(""), Encoding::Gsm7);
+        assert_eq!(encoding("Hello {world}!"), Encoding::Gsm7);
+        assert_eq!(encoding("Hello `world`!"), Encoding::Ucs2);
+        assert_eq!(encoding("Привет"), Encoding::Ucs2);
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(len(""), 0);
+        assert_eq!(len("abc"), 3);
+        assert_eq!(len("[abc]"), 7);
+        assert_eq!(len("[abc]ж"), 6);
+        assert_eq!(len("🚀ж"), 3);
+    }
+
+    #[test]
+    fn test_gsm7_segments() {
+        let message = "a".repeat(153 * 2 + 1);
+        let parts: Vec<&str> = segments(&message).collect();
+        assert_eq!(parts, [&message[..153], &message[153..306], "a"]);
+
+        let message = "a".repeat(152) + "€" + &"b".repeat(10);
+        let parts: Vec<&str> = segments(&message).collect();
+        assert_eq!(parts, [&message[..152], &message[152..]]);
+
+        assert_eq!(segment_count(&"€".repeat(80)), 1);
+        assert_eq!(segment_count(&("€".repeat(80) + "a")), 2);
+    }
+
+    #[test]
+    fn test_ucs2_segments() {
+        let message = "ж".repeat(70);
+        assert_eq!(segment_count(&message), 1);
+
+        let message = "ж".repeat(66) + "🚀" + &"ж".repeat(10);
+        let parts: Vec<&str> = segments(&message).collect();
+        assert_eq!(parts, [&message[..132], &message[132..]]);
+        assert_eq!(segments(&message).encoding(), Encoding::Ucs2);
+
+        let message = "ж".repeat(67 * 3);
+        assert_eq!(segment_count(&message), 3);
+    }
+
+    #[test]
+    fn test_segments_add_up() {
+        let message = "The 🚀 goes to the 🌑! [€] ".repeat(20);
+        let joined: String = segments(&message).collect();
+        assert_eq!(joined, message);
+        for segment in segments(&message) {
+            assert!(crate::utf16::len(segment) <= Encoding::Ucs2.multi_limit());
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(segment_count(""), 0);
+        assert_eq!(segments("").next(), None);
+    }
+}
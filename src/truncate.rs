@@ -0,0 +1,200 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+/// The unit in which [`truncate_with`] and [`truncate_words_with`] measure the
+/// length of a string.
+///
+/// This type is only available with the `alloc` feature enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    /// Unicode scalar values, as counted by [`len`](crate::len)
+    Chars,
+    /// Extended grapheme clusters, as counted by
+    /// [`grapheme::len`](crate::grapheme::len)
+    #[cfg(feature = "grapheme")]
+    Graphemes,
+    /// Terminal columns, as counted by [`width::width`](crate::width::width)
+    #[cfg(feature = "width")]
+    Columns,
+}
+
+impl Unit {
+    fn len(self, s: &str) -> usize {
+        match self {
+            Unit::Chars => crate::len(s),
+            #[cfg(feature = "grapheme")]
+            Unit::Graphemes => crate::grapheme::len(s),
+            #[cfg(feature = "width")]
+            Unit::Columns => crate::width::width(s),
+        }
+    }
+
+    fn till(self, s: &str, end: usize) -> &str {
+        match self {
+            Unit::Chars => crate::till(s, end),
+            #[cfg(feature = "grapheme")]
+            Unit::Graphemes => crate::grapheme::till(s, end),
+            #[cfg(feature = "width")]
+            Unit::Columns => crate::width::till_columns(s, end, crate::width::Straddle::Exclude),
+        }
+    }
+}
+
+/// Shortens a string to at most `max` units, marking the cut with a suffix
+///
+/// If the string already fits it is returned as is. Otherwise it is cut so
+/// that the result, including the suffix, is at most `max` units long. When
+/// even the suffix does not fit, the suffix itself is cut.
+///
+/// # Arguments
+///
+/// * `s` - The string to shorten
+/// * `max` - The maximum length of the result
+/// * `suffix` - What to append when the string is cut, e.g. `"…"`
+/// * `unit` - The unit in which to measure lengths
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::{truncate_with, Unit};
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(truncate_with(s, 8, "…", Unit::Chars), "The 🚀 g…");
+/// assert_eq!(truncate_with(s, 20, "…", Unit::Chars), s);
+/// ```
+pub fn truncate_with<'a>(s: &'a str, max: usize, suffix: &str, unit: Unit) -> Cow<'a, str> {
+    truncate(s, max, suffix, unit, false)
+}
+
+/// Shortens a string to at most `max` units at a word boundary, marking the
+/// cut with a suffix
+///
+/// This behaves the same as [`truncate_with`], but moves the cut back to the
+/// last whitespace so no word is cut in half. Whitespace before the suffix is
+/// removed. If the kept part consists of a single word, it is cut in half
+/// anyway.
+///
+/// Only whitespace counts as a word boundary, so text which does not separate
+/// its words with spaces, such as Chinese or Japanese, is cut at any
+/// character just like with [`truncate_with`].
+///
+/// # Arguments
+///
+/// * `s` - The string to shorten
+/// * `max` - The maximum length of the result
+/// * `suffix` - What to append when the string is cut, e.g. `"…"`
+/// * `unit` - The unit in which to measure lengths
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::{truncate_words_with, Unit};
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(truncate_words_with(s, 8, "…", Unit::Chars), "The 🚀…");
+/// assert_eq!(truncate_words_with(s, 4, "...", Unit::Chars), "T...");
+/// ```
+pub fn truncate_words_with<'a>(s: &'a str, max: usize, suffix: &str, unit: Unit) -> Cow<'a, str> {
+    truncate(s, max, suffix, unit, true)
+}
+
+fn truncate<'a>(s: &'a str, max: usize, suffix: &str, unit: Unit, at_word: bool) -> Cow<'a, str> {
+    if unit.len(s) <= max {
+        return Cow::Borrowed(s);
+    }
+
+    let suffix = unit.till(suffix, max);
+    let mut kept = unit.till(s, max - unit.len(suffix));
+
+    if at_word {
+        let cuts_word = s[kept.len()..]
+            .chars()
+            .next()
+            .filter(|c| !c.is_whitespace())
+            .is_some();
+        if let Some(pos) = kept.rfind(char::is_whitespace).filter(|_| cuts_word) {
+            kept = &kept[..pos];
+        }
+        kept = kept.trim_end();
+    }
+
+    let mut truncated = String::with_capacity(kept.len() + suffix.len());
+    truncated.push_str(kept);
+    truncated.push_str(suffix);
+    Cow::Owned(truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_with() {
+        assert_eq!(truncate_with("abcdef", 6, "…", Unit::Chars), "abcdef");
+        assert_eq!(truncate_with("abcdef", 5, "…", Unit::Chars), "abcd…");
+        assert_eq!(
+            truncate_with("\u{345}ab\u{898}xyz", 5, "..", Unit::Chars),
+            "\u{345}ab.."
+        );
+        assert_eq!(truncate_with("abcdef", 2, "...", Unit::Chars), "..");
+        assert_eq!(truncate_with("abcdef", 0, "…", Unit::Chars), "");
+        assert_eq!(truncate_with("abcdef", 3, "", Unit::Chars), "abc");
+    }
+
+    #[test]
+    fn test_borrows_when_fitting() {
+        assert!(matches!(
+            truncate_with("abc", 3, "…", Unit::Chars),
+            Cow::Borrowed("abc")
+        ));
+        assert!(matches!(
+            truncate_words_with("abc", 5, "…", Unit::Chars),
+            Cow::Borrowed("abc")
+        ));
+    }
+
+    #[test]
+    fn test_result_fits() {
+        let s = "The 🚀 goes to the 🌑!";
+        for max in 0..25 {
+            for suffix in ["", "…", "..."].iter() {
+                assert!(crate::len(&truncate_with(s, max, suffix, Unit::Chars)) <= max);
+                assert!(crate::len(&truncate_words_with(s, max, suffix, Unit::Chars)) <= max);
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncate_words_with() {
+        let s = "hello big world";
+        assert_eq!(truncate_words_with(s, 10, "…", Unit::Chars), "hello big…");
+        assert_eq!(truncate_words_with(s, 9, "…", Unit::Chars), "hello…");
+        assert_eq!(truncate_words_with(s, 7, "…", Unit::Chars), "hello…");
+        assert_eq!(truncate_words_with(s, 5, "…", Unit::Chars), "hell…");
+        assert_eq!(truncate_words_with("a   b", 4, "…", Unit::Chars), "a…");
+        assert_eq!(
+            truncate_words_with("火箭飞向月球", 4, "…", Unit::Chars),
+            "火箭飞…"
+        );
+    }
+
+    #[cfg(feature = "grapheme")]
+    #[test]
+    fn test_graphemes() {
+        let s = "👨‍🚀👨‍🚀👨‍🚀";
+        assert_eq!(truncate_with(s, 3, "…", Unit::Graphemes), s);
+        assert_eq!(truncate_with(s, 2, "…", Unit::Graphemes), "👨‍🚀…");
+    }
+
+    #[cfg(feature = "width")]
+    #[test]
+    fn test_columns() {
+        let s = "中文中文";
+        assert_eq!(truncate_with(s, 8, "…", Unit::Columns), s);
+        assert_eq!(truncate_with(s, 7, "…", Unit::Columns), "中文中…");
+        assert_eq!(truncate_with(s, 6, "…", Unit::Columns), "中文…");
+    }
+}
@@ -0,0 +1,296 @@
+//! Slice utilities which count UTF-16 code units.
+//!
+//! JavaScript strings and the Language Server Protocol index text in UTF-16
+//! code units. Characters outside of the Basic Multilingual Plane, such as
+//! `'🚀'`, take up two code units (a surrogate pair) there, while they are a
+//! single character for the top-level functions of this crate.
+//!
+//! An index which points between the two halves of a surrogate pair cannot be
+//! represented in a `&str` and results in a
+//! [`SliceError::SplitsSurrogatePair`].
+
+use crate::SliceError;
+
+/// Fetches a slice of a string from a begin to an end index
+/// counted in UTF-16 code units.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::{utf16, SliceError};
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf16::slice(s, 4, 6), Ok("🚀"));
+/// assert_eq!(
+///     utf16::slice(s, 4, 5),
+///     Err(SliceError::SplitsSurrogatePair { index: 5 })
+/// );
+/// ```
+pub fn slice(s: &str, begin: usize, end: usize) -> Result<&str, SliceError> {
+    if end < begin {
+        return Err(SliceError::BeginAfterEnd { begin, end });
+    }
+
+    let start_pos = unit_pos(s, begin).map_err(|miss| match miss {
+        Miss::OutOfBounds => SliceError::BeginOutOfBounds { begin, len: len(s) },
+        Miss::InsidePair => SliceError::SplitsSurrogatePair { index: begin },
+    })?;
+
+    let end_pos = unit_pos(&s[start_pos..], end - begin).map_err(|miss| match miss {
+        Miss::OutOfBounds => SliceError::EndOutOfBounds { end, len: len(s) },
+        Miss::InsidePair => SliceError::SplitsSurrogatePair { index: end },
+    })?;
+
+    Ok(&s[start_pos..start_pos + end_pos])
+}
+
+/// Fetches a slice of a string from a starting index
+/// counted in UTF-16 code units.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::utf16::from(s, 19), Ok("🌑!"));
+/// ```
+pub fn from(s: &str, begin: usize) -> Result<&str, SliceError> {
+    unit_pos(s, begin)
+        .map(|start_pos| &s[start_pos..])
+        .map_err(|miss| match miss {
+            Miss::OutOfBounds => SliceError::BeginOutOfBounds { begin, len: len(s) },
+            Miss::InsidePair => SliceError::SplitsSurrogatePair { index: begin },
+        })
+}
+
+/// Fetches a slice of a string until an ending index
+/// counted in UTF-16 code units.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end` - Where the slice ends
+///
+/// # Examples
+///
+/// ```
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(utf8_slice::utf16::till(s, 6), Ok("The 🚀"));
+/// ```
+pub fn till(s: &str, end: usize) -> Result<&str, SliceError> {
+    unit_pos(s, end)
+        .map(|end_pos| &s[..end_pos])
+        .map_err(|miss| match miss {
+            Miss::OutOfBounds => SliceError::EndOutOfBounds { end, len: len(s) },
+            Miss::InsidePair => SliceError::SplitsSurrogatePair { index: end },
+        })
+}
+
+/// Fetches the length in UTF-16 code units of an utf8/unicode string
+///
+/// # Arguments
+///
+/// * `s` - The string of which to fetch the length
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(utf8_slice::utf16::len("🚀"), 2);
+/// assert_eq!(utf8_slice::utf16::len("abc"), 3);
+/// ```
+pub fn len(s: &str) -> usize {
+    s.chars().map(char::len_utf16).sum()
+}
+
+/// Converts an index counted in UTF-16 code units into the byte position of
+/// that code unit.
+///
+/// The length of the string in code units is a valid index and maps to the
+/// length of the string in bytes.
+///
+/// # Arguments
+///
+/// * `s` - The string in which to look up the code unit
+/// * `unit_idx` - The code unit index to convert
+///
+/// # Examples
+///
+/// ```
+/// let s = "🚀 to 🌑";
+///
+/// assert_eq!(utf8_slice::utf16::unit_to_byte(s, 2), Some(4));
+/// assert_eq!(utf8_slice::utf16::unit_to_byte(s, 1), None);
+/// ```
+///
+/// # Note
+/// * Will return `None` for out of range indices and indices which lie within
+///   a surrogate pair *
+pub fn unit_to_byte(s: &str, unit_idx: usize) -> Option<usize> {
+    unit_pos(s, unit_idx).ok()
+}
+
+/// Converts a byte position into an index counted in UTF-16 code units.
+///
+/// The length of the string in bytes is a valid position and maps to the
+/// length of the string in code units.
+///
+/// # Arguments
+///
+/// * `s` - The string in which to look up the byte position
+/// * `byte_idx` - The byte position to convert
+///
+/// # Examples
+///
+/// ```
+/// let s = "🚀 to 🌑";
+///
+/// assert_eq!(utf8_slice::utf16::byte_to_unit(s, 4), Some(2));
+/// assert_eq!(utf8_slice::utf16::byte_to_unit(s, 1), None);
+/// ```
+///
+/// # Note
+/// * Will return `None` for positions which are not on a character boundary *
+pub fn byte_to_unit(s: &str, byte_idx: usize) -> Option<usize> {
+    if !s.is_char_boundary(byte_idx) {
+        return None;
+    }
+
+    Some(len(&s[..byte_idx]))
+}
+
+/// The reason a code unit index could not be converted into a byte position
+pub(crate) enum Miss {
+    OutOfBounds,
+    InsidePair,
+}
+
+/// Converts a code unit index into a byte position
+pub(crate) fn unit_pos(s: &str, unit_idx: usize) -> Result<usize, Miss> {
+    let mut units = 0;
+    for (pos, c) in s.char_indices() {
+        if units == unit_idx {
+            return Ok(pos);
+        }
+
+        units += c.len_utf16();
+        if units > unit_idx {
+            return Err(Miss::InsidePair);
+        }
+    }
+
+    if units == unit_idx {
+        Ok(s.len())
+    } else {
+        Err(Miss::OutOfBounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_as_char_slice_for_bmp() {
+        let s = "\u{345}ab\u{898}xyz";
+        for i in 0..9 {
+            for j in i..9 {
+                assert_eq!(slice(s, i, j).ok(), crate::try_slice(s, i, j).ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        assert_eq!(slice("a🚀b", 1, 3), Ok("🚀"));
+        assert_eq!(slice("a🚀b", 0, 4), Ok("a🚀b"));
+        assert_eq!(slice("a🚀b", 3, 3), Ok(""));
+        assert_eq!(
+            slice("a🚀b", 2, 4),
+            Err(SliceError::SplitsSurrogatePair { index: 2 })
+        );
+        assert_eq!(
+            slice("a🚀b", 0, 2),
+            Err(SliceError::SplitsSurrogatePair { index: 2 })
+        );
+        assert_eq!(
+            slice("a🚀b", 3, 1),
+            Err(SliceError::BeginAfterEnd { begin: 3, end: 1 })
+        );
+        assert_eq!(
+            slice("a🚀b", 5, 6),
+            Err(SliceError::BeginOutOfBounds { begin: 5, len: 4 })
+        );
+        assert_eq!(
+            slice("a🚀b", 1, 6),
+            Err(SliceError::EndOutOfBounds { end: 6, len: 4 })
+        );
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(from("a🚀b", 1), Ok("🚀b"));
+        assert_eq!(from("a🚀b", 3), Ok("b"));
+        assert_eq!(from("a🚀b", 4), Ok(""));
+        assert_eq!(
+            from("a🚀b", 2),
+            Err(SliceError::SplitsSurrogatePair { index: 2 })
+        );
+        assert_eq!(
+            from("a🚀b", 5),
+            Err(SliceError::BeginOutOfBounds { begin: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn test_till() {
+        assert_eq!(till("a🚀b", 3), Ok("a🚀"));
+        assert_eq!(till("a🚀b", 0), Ok(""));
+        assert_eq!(
+            till("a🚀b", 2),
+            Err(SliceError::SplitsSurrogatePair { index: 2 })
+        );
+        assert_eq!(
+            till("a🚀b", 5),
+            Err(SliceError::EndOutOfBounds { end: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(len(""), 0);
+        assert_eq!(len("👨‍🚀"), 5);
+        assert_eq!(len("\u{345}ab\u{898}xyz"), 7);
+        for s in ["", "👨‍🚀", "a🚀b\u{898}"].iter() {
+            assert_eq!(len(s), s.encode_utf16().count());
+        }
+    }
+
+    #[test]
+    fn test_offset_conversion() {
+        let s = "a🚀b\u{898}";
+        assert_eq!(unit_to_byte(s, 0), Some(0));
+        assert_eq!(unit_to_byte(s, 1), Some(1));
+        assert_eq!(unit_to_byte(s, 2), None);
+        assert_eq!(unit_to_byte(s, 3), Some(5));
+        assert_eq!(unit_to_byte(s, 5), Some(s.len()));
+        assert_eq!(unit_to_byte(s, 6), None);
+
+        assert_eq!(byte_to_unit(s, 1), Some(1));
+        assert_eq!(byte_to_unit(s, 3), None);
+        assert_eq!(byte_to_unit(s, 5), Some(3));
+        assert_eq!(byte_to_unit(s, s.len()), Some(5));
+    }
+}
@@ -0,0 +1,254 @@
+//! Slice utilities which count terminal columns.
+//!
+//! Characters are measured by their East Asian Width as defined by
+//! [UAX #11](https://www.unicode.org/reports/tr11/). Most characters take up a
+//! single column, wide characters such as `'中'` take up two columns and
+//! combining marks and control characters take up none. Zero width characters
+//! stay attached to the character before them when slicing.
+//!
+//! This module is only available with the `width` feature enabled.
+
+use unicode_width::UnicodeWidthChar;
+
+/// What to do with a wide character which straddles the column at which a
+/// string is cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Straddle {
+    /// Leave the character out, so the slice never spans more columns than
+    /// requested
+    Exclude,
+    /// Keep the character, so the slice may span one column more than
+    /// requested on each side
+    Include,
+}
+
+/// Fetches the width in terminal columns of an utf8/unicode string
+///
+/// This is the sum of the widths of all characters of the string.
+///
+/// # Arguments
+///
+/// * `s` - The string of which to fetch the width
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(utf8_slice::width::width("abc"), 3);
+/// assert_eq!(utf8_slice::width::width("中文"), 4);
+/// assert_eq!(utf8_slice::width::width("e\u{301}"), 1);
+/// ```
+pub fn width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Fetches a slice of a string from a begin to an end column
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `start_col` - The column where the slice begins
+/// * `end_col` - The column where the slice ends
+/// * `straddle` - What to do with wide characters which are cut in half
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::width::{slice_columns, Straddle};
+///
+/// let s = "a中文b";
+///
+/// assert_eq!(slice_columns(s, 1, 5, Straddle::Exclude), "中文");
+/// assert_eq!(slice_columns(s, 2, 4, Straddle::Exclude), "");
+/// assert_eq!(slice_columns(s, 2, 4, Straddle::Include), "中文");
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid columns *
+pub fn slice_columns(s: &str, start_col: usize, end_col: usize, straddle: Straddle) -> &str {
+    if end_col <= start_col {
+        return "";
+    }
+
+    match (
+        start_pos(s, start_col, straddle),
+        end_pos(s, end_col, straddle),
+    ) {
+        (Some(start_pos), end_pos) if start_pos < end_pos => &s[start_pos..end_pos],
+        _ => "",
+    }
+}
+
+/// Fetches a slice of a string from a starting column
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `start_col` - The column where the slice begins
+/// * `straddle` - What to do with a wide character which is cut in half
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::width::{from_columns, Straddle};
+///
+/// assert_eq!(from_columns("a中文b", 2, Straddle::Exclude), "文b");
+/// assert_eq!(from_columns("a中文b", 2, Straddle::Include), "中文b");
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid columns *
+pub fn from_columns(s: &str, start_col: usize, straddle: Straddle) -> &str {
+    start_pos(s, start_col, straddle)
+        .map(|start_pos| &s[start_pos..])
+        .unwrap_or("")
+}
+
+/// Fetches a slice of a string until an ending column
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end_col` - The column where the slice ends
+/// * `straddle` - What to do with a wide character which is cut in half
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::width::{till_columns, Straddle};
+///
+/// assert_eq!(till_columns("a中文b", 4, Straddle::Exclude), "a中");
+/// assert_eq!(till_columns("a中文b", 4, Straddle::Include), "a中文");
+/// ```
+pub fn till_columns(s: &str, end_col: usize, straddle: Straddle) -> &str {
+    if end_col == 0 {
+        return "";
+    }
+
+    &s[..end_pos(s, end_col, straddle)]
+}
+
+/// Fetches the width of a single character, counting control characters as
+/// zero columns.
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Finds the byte position of the first character which belongs to a slice
+/// starting at `start_col`.
+fn start_pos(s: &str, start_col: usize, straddle: Straddle) -> Option<usize> {
+    if start_col == 0 {
+        return Some(0);
+    }
+
+    let mut col = 0;
+    for (pos, c) in s.char_indices() {
+        let w = char_width(c);
+        let included = match straddle {
+            Straddle::Exclude => col >= start_col,
+            Straddle::Include => col + w > start_col,
+        };
+
+        if w > 0 && included {
+            return Some(pos);
+        }
+
+        col += w;
+    }
+
+    None
+}
+
+/// Finds the byte position of the first character which no longer belongs to
+/// a slice ending at `end_col`.
+fn end_pos(s: &str, end_col: usize, straddle: Straddle) -> usize {
+    let mut col = 0;
+    for (pos, c) in s.char_indices() {
+        let w = char_width(c);
+        let included = match straddle {
+            Straddle::Exclude => col + w <= end_col,
+            Straddle::Include => col < end_col,
+        };
+
+        if w > 0 && !included {
+            return pos;
+        }
+
+        col += w;
+    }
+
+    s.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_as_char_slice_for_ascii() {
+        let s = "xjfdlskfaj sdfjlkj";
+        for i in 0..s.len() + 2 {
+            for j in 0..s.len() + 2 {
+                assert_eq!(
+                    slice_columns(s, i, j, Straddle::Exclude),
+                    crate::slice(s, i, j)
+                );
+                assert_eq!(
+                    slice_columns(s, i, j, Straddle::Include),
+                    crate::slice(s, i, j)
+                );
+            }
+            assert_eq!(from_columns(s, i, Straddle::Exclude), crate::from(s, i));
+            assert_eq!(till_columns(s, i, Straddle::Exclude), crate::till(s, i));
+        }
+    }
+
+    #[test]
+    fn test_width() {
+        assert_eq!(width(""), 0);
+        assert_eq!(width("a中文b"), 6);
+        assert_eq!(width("e\u{301}\u{302}"), 1);
+        assert_eq!(width("a\tb"), 2);
+    }
+
+    #[test]
+    fn test_slice_columns() {
+        let s = "a中文b";
+        assert_eq!(slice_columns(s, 0, 3, Straddle::Exclude), "a中");
+        assert_eq!(slice_columns(s, 0, 2, Straddle::Exclude), "a");
+        assert_eq!(slice_columns(s, 0, 2, Straddle::Include), "a中");
+        assert_eq!(slice_columns(s, 2, 6, Straddle::Exclude), "文b");
+        assert_eq!(slice_columns(s, 2, 6, Straddle::Include), "中文b");
+        assert_eq!(slice_columns(s, 2, 3, Straddle::Exclude), "");
+        assert_eq!(slice_columns(s, 2, 3, Straddle::Include), "中");
+        assert_eq!(slice_columns(s, 6, 8, Straddle::Include), "");
+        assert_eq!(slice_columns(s, 4, 2, Straddle::Include), "");
+    }
+
+    #[test]
+    fn test_zero_width() {
+        let s = "e\u{301}x\u{302}y";
+        assert_eq!(slice_columns(s, 0, 1, Straddle::Exclude), "e\u{301}");
+        assert_eq!(slice_columns(s, 1, 2, Straddle::Exclude), "x\u{302}");
+        assert_eq!(slice_columns(s, 1, 2, Straddle::Include), "x\u{302}");
+        assert_eq!(from_columns(s, 2, Straddle::Exclude), "y");
+        assert_eq!(till_columns(s, 2, Straddle::Include), "e\u{301}x\u{302}");
+        assert_eq!(from_columns("\u{301}x", 0, Straddle::Exclude), "\u{301}x");
+        assert_eq!(till_columns("\u{301}a", 0, Straddle::Exclude), "");
+        assert_eq!(till_columns("\u{301}a", 0, Straddle::Include), "");
+    }
+
+    #[test]
+    fn test_from_columns() {
+        assert_eq!(from_columns("a中文b", 1, Straddle::Exclude), "中文b");
+        assert_eq!(from_columns("a中文b", 5, Straddle::Exclude), "b");
+        assert_eq!(from_columns("a中文b", 6, Straddle::Include), "");
+    }
+
+    #[test]
+    fn test_till_columns() {
+        assert_eq!(till_columns("a中文b", 0, Straddle::Include), "");
+        assert_eq!(till_columns("a中文b", 1, Straddle::Include), "a");
+        assert_eq!(till_columns("a中文b", 2, Straddle::Exclude), "a");
+        assert_eq!(till_columns("a中文b", 10, Straddle::Exclude), "a中文b");
+    }
+}
@@ -0,0 +1,259 @@
+//! Word aware versions of the slice utilities.
+//!
+//! Words are found with the word boundaries defined by
+//! [UAX #29](https://www.unicode.org/reports/tr29/), so `"can't"` and `"3.14"`
+//! are single words and every CJK ideograph is a word of its own. Whitespace
+//! never counts as a word, while runs of punctuation and symbols only count
+//! as a word with [`Punctuation::Include`].
+//!
+//! A slice runs from the start of its first word until the end of its last
+//! word. The separators between those words are part of the slice, the
+//! separators before the first and after the last word are not.
+//!
+//! This module is only available with the `words` feature enabled.
+
+use core::iter::Peekable;
+use unicode_segmentation::{UWordBoundIndices, UnicodeSegmentation};
+
+/// Whether runs of punctuation and symbols, such as `"..."` or `"🚀"`, count
+/// as words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Punctuation {
+    /// Punctuation is treated as a separator between words
+    Skip,
+    /// Every run of punctuation without whitespace in between counts as a
+    /// single word
+    Include,
+}
+
+/// Fetches a slice of a string from a begin to an end index
+/// taking into account word indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `end` - Where the slice ends
+/// * `punctuation` - Whether punctuation counts as words
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::words::{self, Punctuation};
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(words::slice(s, 1, 3, Punctuation::Skip), "goes to");
+/// assert_eq!(words::slice(s, 1, 3, Punctuation::Include), "🚀 goes");
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn slice(s: &str, begin: usize, end: usize, punctuation: Punctuation) -> &str {
+    if end <= begin {
+        return "";
+    }
+
+    let mut words = Words::new(s, punctuation);
+    match words.nth(begin) {
+        Some((start_pos, first_end)) => {
+            let end_pos = words
+                .take(end - begin - 1)
+                .last()
+                .map_or(first_end, |(_, end_pos)| end_pos);
+            &s[start_pos..end_pos]
+        }
+        None => "",
+    }
+}
+
+/// Fetches a slice of a string from a starting index
+/// taking into account word indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `begin` - Where the slice begins
+/// * `punctuation` - Whether punctuation counts as words
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::words::{self, Punctuation};
+///
+/// let s = "The 🚀 goes to the 🌑!";
+///
+/// assert_eq!(words::from(s, 3, Punctuation::Skip), "the");
+/// assert_eq!(words::from(s, 3, Punctuation::Include), "to the 🌑!");
+/// ```
+///
+/// # Note
+/// * Will return an empty string for invalid indices *
+pub fn from(s: &str, begin: usize, punctuation: Punctuation) -> &str {
+    slice(s, begin, usize::MAX, punctuation)
+}
+
+/// Fetches a slice of a string until an ending index
+/// taking into account word indices.
+///
+/// # Arguments
+///
+/// * `s` - An input string to take the slice from
+/// * `end` - Where the slice ends
+/// * `punctuation` - Whether punctuation counts as words
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::words::{self, Punctuation};
+///
+/// let s = "Hello, wörld! 你好";
+///
+/// assert_eq!(words::till(s, 3, Punctuation::Skip), "Hello, wörld! 你");
+/// assert_eq!(words::till(s, 3, Punctuation::Include), "Hello, wörld");
+/// ```
+pub fn till(s: &str, end: usize, punctuation: Punctuation) -> &str {
+    slice(s, 0, end, punctuation)
+}
+
+/// Fetches the length in words of an utf8/unicode string
+///
+/// # Arguments
+///
+/// * `s` - The string of which to fetch the length
+/// * `punctuation` - Whether punctuation counts as words
+///
+/// # Examples
+///
+/// ```
+/// use utf8_slice::words::{self, Punctuation};
+///
+/// assert_eq!(words::len("Don't panic...", Punctuation::Skip), 2);
+/// assert_eq!(words::len("Don't panic...", Punctuation::Include), 3);
+/// ```
+pub fn len(s: &str, punctuation: Punctuation) -> usize {
+    Words::new(s, punctuation).count()
+}
+
+/// An iterator over the byte spans of the words in a string
+struct Words<'a> {
+    segments: Peekable<UWordBoundIndices<'a>>,
+    punctuation: Punctuation,
+}
+
+impl<'a> Words<'a> {
+    fn new(s: &'a str, punctuation: Punctuation) -> Self {
+        Words {
+            segments: s.split_word_bound_indices().peekable(),
+            punctuation,
+        }
+    }
+}
+
+impl Iterator for Words<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (start_pos, segment) = self.segments.next()?;
+            let mut end_pos = start_pos + segment.len();
+
+            if is_word(segment) {
+                return Some((start_pos, end_pos));
+            }
+
+            if self.punctuation == Punctuation::Skip || is_whitespace(segment) {
+                continue;
+            }
+
+            while let Some(&(_, segment)) = self.segments.peek() {
+                if is_word(segment) || is_whitespace(segment) {
+                    break;
+                }
+
+                end_pos += segment.len();
+                self.segments.next();
+            }
+
+            return Some((start_pos, end_pos));
+        }
+    }
+}
+
+/// Whether a word segment contains a letter or digit, which is what
+/// [`UnicodeSegmentation::unicode_words`] considers a word
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
+fn is_whitespace(segment: &str) -> bool {
+    segment.chars().all(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_same_as_unicode_words() {
+        let strings = [
+            "",
+            "The quick (\"brown\") fox can't jump 32.3 feet, right?",
+            "Hello, wörld! 你好世界 🚀🚀 --- e\u{301}tude",
+        ];
+
+        for s in strings.iter() {
+            let expected: Vec<&str> = s.unicode_words().collect();
+            assert_eq!(len(s, Punctuation::Skip), expected.len());
+            for (i, word) in expected.iter().enumerate() {
+                assert_eq!(slice(s, i, i + 1, Punctuation::Skip), *word);
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        let s = "  The quick, brown fox!  ";
+        assert_eq!(slice(s, 0, 1, Punctuation::Skip), "The");
+        assert_eq!(slice(s, 1, 3, Punctuation::Skip), "quick, brown");
+        assert_eq!(slice(s, 2, 10, Punctuation::Skip), "brown fox");
+        assert_eq!(slice(s, 4, 5, Punctuation::Skip), "");
+        assert_eq!(slice(s, 2, 2, Punctuation::Skip), "");
+        assert_eq!(slice(s, 3, 1, Punctuation::Skip), "");
+
+        assert_eq!(slice(s, 1, 3, Punctuation::Include), "quick,");
+        assert_eq!(slice(s, 2, 10, Punctuation::Include), ", brown fox!");
+        assert_eq!(slice(s, 5, 6, Punctuation::Include), "!");
+        assert_eq!(slice(s, 6, 7, Punctuation::Include), "");
+    }
+
+    #[test]
+    fn test_punctuation_runs() {
+        let s = "Wait...!? What -- really";
+        assert_eq!(len(s, Punctuation::Skip), 3);
+        assert_eq!(len(s, Punctuation::Include), 5);
+        assert_eq!(slice(s, 1, 2, Punctuation::Include), "...!?");
+        assert_eq!(slice(s, 3, 4, Punctuation::Include), "--");
+    }
+
+    #[test]
+    fn test_cjk() {
+        let s = "你好，世界。";
+        assert_eq!(len(s, Punctuation::Skip), 4);
+        assert_eq!(slice(s, 1, 3, Punctuation::Skip), "好，世");
+        assert_eq!(len(s, Punctuation::Include), 6);
+        assert_eq!(slice(s, 2, 3, Punctuation::Include), "，");
+    }
+
+    #[test]
+    fn test_from_till() {
+        let s = "one two three";
+        assert_eq!(from(s, 0, Punctuation::Skip), s);
+        assert_eq!(from(s, 2, Punctuation::Skip), "three");
+        assert_eq!(from(s, 3, Punctuation::Skip), "");
+        assert_eq!(till(s, 0, Punctuation::Skip), "");
+        assert_eq!(till(s, 2, Punctuation::Skip), "one two");
+        assert_eq!(till(s, 5, Punctuation::Skip), s);
+    }
+}